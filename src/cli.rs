@@ -1,9 +1,11 @@
 use clap::{Args, Parser, Subcommand};
+use rsp::engine::builtins::list::DEFAULT_MAX_LIST_SIZE;
+use rsp::engine::eval::DEFAULT_MAX_EVAL_DEPTH;
 use std::path::PathBuf;
 
 /// A simple Lisp interpreter written in Rust.
 #[derive(Parser, Debug)]
-#[clap(author, version, about, long_about = None)]
+#[clap(author, version = env!("CARGO_PKG_VERSION"), about, long_about = None)]
 #[clap(name = "rsp", bin_name = "rsp")]
 #[clap(subcommand_required = true, arg_required_else_help = true)] // Ensures a subcommand is given, or help is printed.
 pub struct Cli {
@@ -35,4 +37,78 @@ pub struct RunArgs {
         required_unless_present = "expr"
     )]
     pub file: Option<PathBuf>,
+
+    /// Maximum evaluation depth before failing with a stack overflow error.
+    /// 0 means unlimited, which lets runaway recursion crash the interpreter.
+    #[clap(long, value_name = "N", default_value_t = DEFAULT_MAX_EVAL_DEPTH)]
+    pub max_depth: usize,
+
+    /// Number of significant digits used when printing numbers.
+    /// Defaults to the shortest representation that round-trips exactly.
+    #[clap(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub precision: Option<usize>,
+
+    /// Maximum number of elements builtins that build a list of a requested size, such as
+    /// `list/range`, may produce. 0 means unlimited, which lets an accidental huge list
+    /// exhaust memory.
+    #[clap(long, value_name = "N", default_value_t = DEFAULT_MAX_LIST_SIZE)]
+    pub max_list_size: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_max_depth_defaults() {
+        let cli = Cli::try_parse_from(["rsp", "run", "-e", "(+ 1 2)"]).unwrap();
+        match cli.command {
+            Commands::Run(run_args) => assert_eq!(run_args.max_depth, DEFAULT_MAX_EVAL_DEPTH),
+            other => panic!("Expected run command, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn run_max_depth_parses() {
+        let cli =
+            Cli::try_parse_from(["rsp", "run", "--max-depth", "64", "-e", "(+ 1 2)"]).unwrap();
+        match cli.command {
+            Commands::Run(run_args) => assert_eq!(run_args.max_depth, 64),
+            other => panic!("Expected run command, got {:?}", other),
+        }
+
+        assert!(Cli::try_parse_from(["rsp", "run", "--max-depth", "-1", "-e", "1"]).is_err());
+    }
+
+    #[test]
+    fn run_precision_parses() {
+        let cli = Cli::try_parse_from(["rsp", "run", "-e", "1"]).unwrap();
+        match cli.command {
+            Commands::Run(run_args) => assert_eq!(run_args.precision, None),
+            other => panic!("Expected run command, got {:?}", other),
+        }
+
+        let cli = Cli::try_parse_from(["rsp", "run", "--precision", "4", "-e", "1"]).unwrap();
+        match cli.command {
+            Commands::Run(run_args) => assert_eq!(run_args.precision, Some(4)),
+            other => panic!("Expected run command, got {:?}", other),
+        }
+
+        assert!(Cli::try_parse_from(["rsp", "run", "--precision", "0", "-e", "1"]).is_err());
+    }
+
+    #[test]
+    fn run_max_list_size_parses() {
+        let cli = Cli::try_parse_from(["rsp", "run", "-e", "1"]).unwrap();
+        match cli.command {
+            Commands::Run(run_args) => assert_eq!(run_args.max_list_size, DEFAULT_MAX_LIST_SIZE),
+            other => panic!("Expected run command, got {:?}", other),
+        }
+
+        let cli = Cli::try_parse_from(["rsp", "run", "--max-list-size", "500", "-e", "1"]).unwrap();
+        match cli.command {
+            Commands::Run(run_args) => assert_eq!(run_args.max_list_size, 500),
+            other => panic!("Expected run command, got {:?}", other),
+        }
+    }
 }
@@ -1,10 +1,12 @@
 use crate::engine::env::Environment;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
 #[derive(Clone)]
 pub struct LispFunction {
+    pub name: Option<String>, // Set by the `let` that first binds the function, e.g. for `profile`
     pub params: Vec<String>,
     pub body: Box<Expr>,
     pub closure: Rc<RefCell<Environment>>,
@@ -13,6 +15,7 @@ pub struct LispFunction {
 impl fmt::Debug for LispFunction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("LispFunction")
+            .field("name", &self.name)
             .field("params", &self.params)
             .field("body", &self.body)
             .field("closure", &"<captured_env>") // Avoid printing the whole env
@@ -21,7 +24,7 @@ impl fmt::Debug for LispFunction {
 }
 
 // Functions are equal if their parameters and body are structurally equal.
-// The captured environment is not considered for this PartialEq.
+// The name and captured environment are not considered for this PartialEq.
 impl PartialEq for LispFunction {
     fn eq(&self, other: &Self) -> bool {
         self.params == other.params && self.body == other.body
@@ -32,9 +35,12 @@ impl PartialEq for LispFunction {
 pub enum Expr {
     Symbol(String),
     Number(f64),
+    Rational { num: i64, den: i64 }, // Exact fraction; build with `Expr::rational` to normalize
     List(Vec<Expr>),
+    Vector(Vec<Expr>), // Indexed sequence built by `list->vector`; evaluates to itself
     Function(LispFunction),
     NativeFunction(NativeFunction), // New variant for Rust functions
+    NativeClosure(NativeClosure),   // Rust closures that capture state (e.g. `memoize`)
     Bool(bool),
     Nil,
     String(String),     // New variant for string literals
@@ -43,22 +49,156 @@ pub enum Expr {
 
 impl Expr {
     /// Provides a user-friendly string representation of an expression, suitable for printing.
+    /// Numbers are always rendered exactly, since this is also how values are converted to
+    /// strings (e.g. by `number->string` or `string/format`).
     pub fn to_lisp_string(&self) -> String {
+        self.render(None, false)
+    }
+
+    /// Renders the expression for display, as in `log` output: like `to_lisp_string`, but
+    /// numbers are rounded to the precision set with `set_number_precision`.
+    pub fn to_display_string(&self) -> String {
+        self.render(NUMBER_PRECISION.with(Cell::get), false)
+    }
+
+    /// Renders an evaluation result, as printed by the CLI and REPL: like `to_display_string`,
+    /// but strings are quoted so they can be told apart from symbols.
+    pub fn to_result_string(&self) -> String {
+        self.render(NUMBER_PRECISION.with(Cell::get), true)
+    }
+
+    // Shared renderer: numbers use `precision` significant digits, and strings are quoted and
+    // escaped when `quote_strings` is set.
+    fn render(&self, precision: Option<usize>, quote_strings: bool) -> String {
         match self {
             Expr::Symbol(s) => s.clone(),
-            Expr::Number(n) => n.to_string(),
+            Expr::Number(n) => format_number(*n, precision),
+            Expr::Rational { num, den: 1 } => num.to_string(),
+            Expr::Rational { num, den } => format!("{}/{}", num, den),
             Expr::List(list) => {
-                let sexprs: Vec<String> = list.iter().map(|exp| exp.to_lisp_string()).collect();
+                let sexprs: Vec<String> = list
+                    .iter()
+                    .map(|exp| exp.render(precision, quote_strings))
+                    .collect();
                 format!("({})", sexprs.join(" "))
             }
+            Expr::Vector(items) => {
+                let elements: Vec<String> = items
+                    .iter()
+                    .map(|exp| exp.render(precision, quote_strings))
+                    .collect();
+                format!("[{}]", elements.join(" "))
+            }
             Expr::Function(_) => "<function>".to_string(), // Simplified representation
             Expr::NativeFunction(nf) => format!("<native_function:{}>", nf.name),
+            Expr::NativeClosure(nc) => format!("<native_function:{}>", nc.name),
             Expr::Bool(b) => b.to_string(),
             Expr::Nil => "nil".to_string(),
+            Expr::String(s) if quote_strings => format!("{:?}", s),
             Expr::String(s) => s.clone(), // For strings, return their content
-            Expr::Module(m) => format!("<module:{}>", m.path.display()),
+            Expr::Module(m) => format!("#module[{}]", m.path.display()),
         }
     }
+
+    /// Builds a normalized rational: reduced by the gcd, with the sign kept in the numerator.
+    /// A whole value (denominator 1) becomes an `Expr::Number`, so `1/2 + 1/2` is equal to `1`
+    /// and usable wherever an integer is expected.
+    /// Returns `None` for a zero denominator or when normalizing would overflow `i64`.
+    pub fn rational(num: i64, den: i64) -> Option<Expr> {
+        if den == 0 {
+            return None;
+        }
+        let divisor = gcd(num, den);
+        let (mut num, mut den) = (num / divisor, den / divisor);
+        if den < 0 {
+            num = num.checked_neg()?;
+            den = den.checked_neg()?;
+        }
+        if den == 1 {
+            return Some(Expr::Number(num as f64));
+        }
+        Some(Expr::Rational { num, den })
+    }
+}
+
+// Greatest common divisor, always positive for a nonzero `b`.
+fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    // A gcd of 2^63 only arises from i64::MIN operands; 1 leaves the overflow to the caller's checks.
+    i64::try_from(a).unwrap_or(1)
+}
+
+// Hashing mirrors `PartialEq`, so equal values always hash equally: functions hash their
+// params and body, natives their name, closures their identity and modules their path.
+impl Hash for Expr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Expr::Symbol(s) | Expr::String(s) => s.hash(state),
+            // Floats hash by bit pattern, with -0.0 folded into 0.0 since the two compare equal.
+            Expr::Number(n) => {
+                let n = if *n == 0.0 { 0.0 } else { *n };
+                n.to_bits().hash(state);
+            }
+            Expr::Rational { num, den } => (num, den).hash(state),
+            Expr::List(items) | Expr::Vector(items) => items.hash(state),
+            Expr::Function(f) => {
+                f.params.hash(state);
+                f.body.hash(state);
+            }
+            Expr::NativeFunction(nf) => nf.name.hash(state),
+            Expr::NativeClosure(nc) => std::ptr::hash(Rc::as_ptr(&nc.func) as *const (), state),
+            Expr::Bool(b) => b.hash(state),
+            Expr::Nil => {}
+            Expr::Module(m) => m.path.hash(state),
+        }
+    }
+}
+
+thread_local! {
+    // Significant digits used when rendering numbers on this thread; None means full precision.
+    static NUMBER_PRECISION: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Sets how many significant digits `to_display_string` and `to_result_string` use for numbers
+/// on the current thread.
+/// `None` restores the default, shortest round-trip representation.
+pub fn set_number_precision(precision: Option<usize>) {
+    NUMBER_PRECISION.with(|cell| cell.set(precision));
+}
+
+/// Formats `n` rounded to `precision` significant digits, dropping trailing zeros.
+/// With no precision (or for zero, infinities and NaN) the default representation is used.
+pub fn format_number(n: f64, precision: Option<usize>) -> String {
+    let Some(digits) = precision.filter(|&digits| digits > 0) else {
+        return n.to_string();
+    };
+    if n == 0.0 || !n.is_finite() {
+        return n.to_string();
+    }
+
+    let digits = digits as i32;
+    let magnitude = n.abs().log10().floor() as i32;
+    // Digits left of the decimal point beyond the precision are rounded away entirely.
+    let rounded = if magnitude >= digits {
+        let scale = 10f64.powi(magnitude - digits + 1);
+        (n / scale).round() * scale
+    } else {
+        n
+    };
+    let decimals = (digits - 1 - magnitude).max(0) as usize;
+    let formatted = format!("{:.*}", decimals, rounded);
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
 }
 
 #[derive(Clone)]
@@ -114,8 +254,107 @@ impl PartialEq for NativeFunction {
     }
 }
 
+/// Type alias for a native Rust closure that can be called from Lisp.
+/// Unlike `NativeFn`, it may capture state, which lets natives such as `memoize`
+/// return new function values built at runtime.
+pub type NativeClosureFn = Rc<dyn Fn(Vec<Expr>) -> Result<Expr, crate::engine::eval::LispError>>;
+
+#[derive(Clone)]
+pub struct NativeClosure {
+    pub name: String, // For debugging and identification
+    pub func: NativeClosureFn,
+}
+
+impl fmt::Debug for NativeClosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeClosure")
+            .field("name", &self.name)
+            .field("func", &"<native_closure>") // Closures have no useful Debug representation
+            .finish()
+    }
+}
+
+// NativeClosures are only equal if they share the same underlying closure.
+// Names are not unique here: every call to `memoize` produces a distinct closure.
+impl PartialEq for NativeClosure {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.func, &other.func)
+    }
+}
+
 // Helper functions for constructing AST nodes can be added here later.
 // For example:
 // pub fn symbol(s: &str) -> Expr { Expr::Symbol(s.to_string()) }
 // pub fn number(n: f64) -> Expr { Expr::Number(n) }
 // pub fn list(elements: Vec<Expr>) -> Expr { Expr::List(elements) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rational_is_normalized() {
+        assert_eq!(
+            Expr::rational(2, 4),
+            Some(Expr::Rational { num: 1, den: 2 })
+        );
+        assert_eq!(
+            Expr::rational(3, -6),
+            Some(Expr::Rational { num: -1, den: 2 })
+        );
+        assert_eq!(Expr::rational(-4, -2), Some(Expr::Number(2.0)));
+        assert_eq!(Expr::rational(0, 5), Some(Expr::Number(0.0)));
+        assert_eq!(Expr::rational(1, 0), None);
+    }
+
+    #[test]
+    fn rational_to_lisp_string() {
+        assert_eq!(Expr::rational(1, 3).unwrap().to_lisp_string(), "1/3");
+        assert_eq!(Expr::rational(-6, 3).unwrap().to_lisp_string(), "-2");
+    }
+
+    #[test]
+    fn format_number_without_precision_uses_default() {
+        assert_eq!(format_number(1.0 / 3.0, None), (1.0f64 / 3.0).to_string());
+        assert_eq!(format_number(42.0, None), "42");
+    }
+
+    #[test]
+    fn format_number_rounds_to_significant_digits() {
+        assert_eq!(format_number(1.0 / 3.0, Some(3)), "0.333");
+        assert_eq!(format_number(1.23456, Some(2)), "1.2");
+        assert_eq!(format_number(-4.56789, Some(4)), "-4.568");
+        assert_eq!(format_number(123456.0, Some(3)), "123000");
+        assert_eq!(format_number(0.000123456, Some(2)), "0.00012");
+        // Rounding can carry into a new leading digit.
+        assert_eq!(format_number(9.99, Some(2)), "10");
+    }
+
+    #[test]
+    fn format_number_drops_trailing_zeros_and_keeps_specials() {
+        assert_eq!(format_number(1.5, Some(5)), "1.5");
+        assert_eq!(format_number(7.0, Some(3)), "7");
+        assert_eq!(format_number(0.0, Some(3)), "0");
+        assert_eq!(format_number(f64::INFINITY, Some(3)), "inf");
+        assert_eq!(format_number(f64::NAN, Some(3)), "NaN");
+    }
+
+    #[test]
+    fn display_strings_respect_thread_precision() {
+        let value = Expr::List(vec![
+            Expr::Number(2.0 / 3.0),
+            Expr::Number(10.0),
+            Expr::String("a b".to_string()),
+        ]);
+        set_number_precision(Some(3));
+        let display = value.to_display_string();
+        let result = value.to_result_string();
+        let exact = value.to_lisp_string();
+        set_number_precision(None);
+        assert_eq!(display, "(0.667 10 a b)");
+        assert_eq!(result, r#"(0.667 10 "a b")"#);
+        // Conversions to strings never round.
+        assert_eq!(exact, "(0.6666666666666666 10 a b)");
+        assert_eq!(Expr::Number(0.5).to_display_string(), "0.5");
+    }
+}
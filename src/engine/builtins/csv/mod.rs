@@ -0,0 +1,307 @@
+//! The `csv` module: reading and writing comma-separated values.
+//!
+//! A CSV document is represented as a list of rows, each a list of string fields. Fields may
+//! be wrapped in double quotes to contain commas, newlines, or doubled (`""`) quotes.
+
+use crate::engine::ast::{Expr, LispModule, NativeFunction};
+use crate::engine::env::Environment;
+use crate::engine::eval::LispError;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::{error, trace};
+
+// Helper function to extract the text argument of a csv function.
+fn extract_text<'a>(expr: &'a Expr, op_name: &str) -> Result<&'a str, LispError> {
+    match expr {
+        Expr::String(s) => Ok(s),
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "String".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native csv function");
+            Err(type_error)
+        }
+    }
+}
+
+// Helper function to borrow the elements of the rows list, or of a single row.
+fn extract_list<'a>(expr: &'a Expr, op_name: &str) -> Result<&'a [Expr], LispError> {
+    match expr {
+        Expr::List(items) => Ok(items),
+        Expr::Nil => Ok(&[]),
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "List".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native csv function");
+            Err(type_error)
+        }
+    }
+}
+
+// Splits CSV text into rows of fields. Rows end at `\n` or `\r\n`; a trailing line break
+// does not start an extra row.
+fn parse_rows(text: &str) -> Result<Vec<Vec<String>>, LispError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                // A doubled quote is an escaped quote; a single one closes the field.
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                other => field.push(other),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            other => field.push(other),
+        }
+    }
+
+    if in_quotes {
+        let msg = "csv/parse found a quoted field that is never closed".to_string();
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    // The last row has no line break after it unless the text ends with one.
+    if !text.is_empty() && !text.ends_with('\n') {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+// Native function for parsing CSV text: (csv/parse s)
+fn native_csv_parse(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native csv function: csv/parse");
+    if args.len() != 1 {
+        let msg = format!("csv/parse expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let text = extract_text(&args[0], "csv/parse")?;
+    let rows = parse_rows(text)?;
+    Ok(Expr::List(
+        rows.into_iter()
+            .map(|row| Expr::List(row.into_iter().map(Expr::String).collect()))
+            .collect(),
+    ))
+}
+
+// Helper: renders one field, quoting it when it contains a comma, quote, or line break.
+fn write_field(value: &Expr, out: &mut String) {
+    let text = value.to_lisp_string();
+    if text.contains([',', '"', '\n', '\r']) {
+        out.push('"');
+        out.push_str(&text.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(&text);
+    }
+}
+
+// Native function for writing rows as CSV text: (csv/write rows)
+// Non-string fields are written as they print; every row ends with a line break.
+fn native_csv_write(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native csv function: csv/write");
+    if args.len() != 1 {
+        let msg = format!("csv/write expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let rows = extract_list(&args[0], "csv/write")?;
+    let mut out = String::new();
+    for row in rows {
+        let fields = extract_list(row, "csv/write")?;
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write_field(field, &mut out);
+        }
+        out.push('\n');
+    }
+    Ok(Expr::String(out))
+}
+
+/// Creates the `csv` module with its associated functions.
+pub fn create_csv_module() -> Expr {
+    trace!("Creating csv module");
+    let csv_env_rc = Environment::new(); // Modules have their own environment
+
+    // Scope the mutable borrow so it's dropped before csv_env_rc is moved
+    {
+        let mut csv_env_borrowed = csv_env_rc.borrow_mut();
+        let functions_to_define: HashMap<String, Expr> = HashMap::from([
+            (
+                "parse".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "csv/parse".to_string(),
+                    func: native_csv_parse,
+                }),
+            ),
+            (
+                "write".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "csv/write".to_string(),
+                    func: native_csv_write,
+                }),
+            ),
+        ]);
+
+        for (name, func_expr) in functions_to_define {
+            csv_env_borrowed.define(name, func_expr);
+        }
+    }
+
+    Expr::Module(LispModule {
+        path: PathBuf::from("builtin:csv"),
+        env: csv_env_rc,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::eval::eval;
+    use crate::engine::parser::parse_expr;
+    use crate::logging::init_test_logging;
+
+    // Helper to evaluate a Lisp string in an environment that includes the csv module.
+    fn eval_csv_str(code: &str) -> Result<Expr, LispError> {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let (remaining, parsed_expr_option) = parse_expr(code)
+            .unwrap_or_else(|e| panic!("Test parse error for code '{}': {}", code, e));
+        assert!(
+            remaining.is_empty(),
+            "Unexpected remaining input after parsing in test for code '{}': {}",
+            code,
+            remaining
+        );
+        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
+        eval(&parsed_expr, env)
+    }
+
+    // Builds the expected list-of-rows value from string slices.
+    fn rows(rows: &[&[&str]]) -> Expr {
+        Expr::List(
+            rows.iter()
+                .map(|row| Expr::List(row.iter().map(|f| Expr::String(f.to_string())).collect()))
+                .collect(),
+        )
+    }
+
+    // Tests for csv/parse
+    #[test]
+    fn test_native_csv_parse_simple_rows() {
+        let result = eval_csv_str(r#"(csv/parse "name,age\nada,36\n")"#);
+        assert_eq!(result, Ok(rows(&[&["name", "age"], &["ada", "36"]])));
+
+        // CRLF line endings and a missing final line break.
+        let result_crlf = eval_csv_str(r#"(csv/parse "a,b\r\nc,d")"#);
+        assert_eq!(result_crlf, Ok(rows(&[&["a", "b"], &["c", "d"]])));
+    }
+
+    #[test]
+    fn test_native_csv_parse_empty_fields() {
+        let result = eval_csv_str(r#"(csv/parse ",x,")"#);
+        assert_eq!(result, Ok(rows(&[&["", "x", ""]])));
+
+        let result_empty = eval_csv_str(r#"(csv/parse "")"#);
+        assert_eq!(result_empty, Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_csv_parse_quoted_field_with_comma() {
+        let result = eval_csv_str(r#"(csv/parse "city,\"Portland, OR\"\n")"#);
+        assert_eq!(result, Ok(rows(&[&["city", "Portland, OR"]])));
+
+        let result_newline = eval_csv_str(r#"(csv/parse "\"line one\nline two\",end")"#);
+        assert_eq!(result_newline, Ok(rows(&[&["line one\nline two", "end"]])));
+    }
+
+    #[test]
+    fn test_native_csv_parse_escaped_quote() {
+        let result = eval_csv_str(r#"(csv/parse "\"she said \"\"hi\"\"\",ok")"#);
+        assert_eq!(result, Ok(rows(&[&["she said \"hi\"", "ok"]])));
+    }
+
+    #[test]
+    fn test_native_csv_parse_errors() {
+        let result_unclosed = eval_csv_str(r#"(csv/parse "\"open,field")"#);
+        assert!(matches!(result_unclosed, Err(LispError::ValueError(_))));
+
+        let result_type = eval_csv_str("(csv/parse 42)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_csv_str("(csv/parse)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for csv/write
+    #[test]
+    fn test_native_csv_write_plain_and_stringified_fields() {
+        let result = eval_csv_str(r#"(csv/write '(("name" "age") ("ada" 36) (sym true nil)))"#);
+        assert_eq!(
+            result,
+            Ok(Expr::String("name,age\nada,36\nsym,true,nil\n".to_string()))
+        );
+
+        assert_eq!(
+            eval_csv_str("(csv/write nil)"),
+            Ok(Expr::String(String::new()))
+        );
+    }
+
+    #[test]
+    fn test_native_csv_write_quotes_fields_when_needed() {
+        let result =
+            eval_csv_str(r#"(csv/write '(("Portland, OR" "say \"hi\"" "two\nlines" "plain")))"#);
+        assert_eq!(
+            result,
+            Ok(Expr::String(
+                "\"Portland, OR\",\"say \"\"hi\"\"\",\"two\nlines\",plain\n".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_native_csv_write_round_trips_with_parse() {
+        let rows_code =
+            r#"'(("id" "note") ("1" "a, b") ("2" "quote \" mark") ("3" "multi\nline") ("4" ""))"#;
+        let round_trip = eval_csv_str(&format!("(csv/parse (csv/write {}))", rows_code));
+        assert_eq!(round_trip, eval_csv_str(rows_code));
+    }
+
+    #[test]
+    fn test_native_csv_write_errors() {
+        let result_not_rows = eval_csv_str("(csv/write \"a,b\")");
+        assert!(matches!(result_not_rows, Err(LispError::TypeError { .. })));
+
+        let result_bad_row = eval_csv_str("(csv/write '(1 2))");
+        assert!(matches!(result_bad_row, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_csv_str("(csv/write)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+}
@@ -0,0 +1,887 @@
+use crate::engine::ast::{Expr, NativeClosure};
+use crate::engine::builtins::list::check_list_size;
+use crate::engine::builtins::value::hash_value;
+use crate::engine::eval::{LispError, apply, is_truthy};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use tracing::{debug, error, trace};
+
+// Cached (arguments, result) pairs of a memoized function, bucketed by the hash of the arguments.
+type MemoCache = HashMap<u64, Vec<(Vec<Expr>, Expr)>>;
+
+// Helper function to check that an argument can be applied, consistent with extract_number
+fn extract_function(expr: &Expr, op_name: &str) -> Result<Expr, LispError> {
+    match expr {
+        Expr::Function(_) | Expr::NativeFunction(_) | Expr::NativeClosure(_) => Ok(expr.clone()),
+        _ => {
+            let type_error = LispError::TypeError {
+                expected: "Function".to_string(),
+                found: format!("{:?}", expr),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native function");
+            Err(type_error)
+        }
+    }
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_identity(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'identity' function");
+    if args.len() != 1 {
+        let msg = format!("identity expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    Ok(args[0].clone())
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_constantly(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'constantly' function");
+    if args.len() != 1 {
+        let msg = format!("constantly expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let value = args[0].clone();
+
+    Ok(Expr::NativeClosure(NativeClosure {
+        name: "constantly".to_string(),
+        // Any number of arguments is accepted and ignored.
+        func: Rc::new(move |_call_args: Vec<Expr>| Ok(value.clone())),
+    }))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_memoize(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'memoize' function");
+    if args.len() != 1 {
+        let msg = format!("memoize expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let func = extract_function(&args[0], "memoize")?;
+
+    let cache: Rc<RefCell<MemoCache>> = Rc::new(RefCell::new(HashMap::new()));
+
+    Ok(Expr::NativeClosure(NativeClosure {
+        name: "memoize".to_string(),
+        func: Rc::new(move |call_args: Vec<Expr>| {
+            // Release the borrow before applying, so recursive calls can consult the cache.
+            let key = hash_value(&call_args);
+            let cached = cache.borrow().get(&key).and_then(|bucket| {
+                bucket
+                    .iter()
+                    .find(|(args, _)| *args == call_args)
+                    .map(|(_, result)| result.clone())
+            });
+            if let Some(result) = cached {
+                debug!(args = ?call_args, "memoize cache hit");
+                return Ok(result);
+            }
+
+            let result = apply(func.clone(), call_args.clone())?;
+            cache
+                .borrow_mut()
+                .entry(key)
+                .or_default()
+                .push((call_args, result.clone()));
+            Ok(result)
+        }),
+    }))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_partial(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'partial' function");
+    if args.is_empty() {
+        let msg = "partial expects at least 1 argument (the function), got 0".to_string();
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let func = extract_function(&args[0], "partial")?;
+    let captured_args: Vec<Expr> = args[1..].to_vec();
+
+    Ok(Expr::NativeClosure(NativeClosure {
+        name: "partial".to_string(),
+        func: Rc::new(move |call_args: Vec<Expr>| {
+            let mut full_args = captured_args.clone();
+            full_args.extend(call_args);
+            apply(func.clone(), full_args)
+        }),
+    }))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_comp(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'comp' function");
+    let mut funcs = Vec::with_capacity(args.len());
+    for (i, arg) in args.iter().enumerate() {
+        funcs.push(extract_function(arg, &format!("comp (arg {})", i + 1))?);
+    }
+
+    Ok(Expr::NativeClosure(NativeClosure {
+        name: "comp".to_string(),
+        func: Rc::new(move |call_args: Vec<Expr>| {
+            // Functions are applied right-to-left: the last one receives the call arguments,
+            // and each earlier one receives the single result of the function after it.
+            let Some((innermost, rest)) = funcs.split_last() else {
+                // (comp) with no functions is the identity function.
+                if call_args.len() != 1 {
+                    let msg = format!(
+                        "identity function from (comp) expects 1 argument, got {}",
+                        call_args.len()
+                    );
+                    error!("{}", msg);
+                    return Err(LispError::ArityMismatch(msg));
+                }
+                return Ok(call_args[0].clone());
+            };
+
+            let mut result = apply(innermost.clone(), call_args)?;
+            for func in rest.iter().rev() {
+                result = apply(func.clone(), vec![result])?;
+            }
+            Ok(result)
+        }),
+    }))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_disassemble(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'disassemble' function");
+    if args.len() != 1 {
+        let msg = format!("disassemble expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    // Only Lisp functions have an AST to show; native functions are opaque Rust code.
+    match &args[0] {
+        Expr::Function(lisp_fn) => Ok(Expr::String(format!(
+            "(fn ({}) {})",
+            lisp_fn.params.join(" "),
+            lisp_fn.body.to_lisp_string()
+        ))),
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Lisp function".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native 'disassemble'");
+            Err(type_error)
+        }
+    }
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_tap(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'tap' function");
+    if args.len() != 2 {
+        let msg = format!("tap expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let func = extract_function(&args[0], "tap")?;
+
+    // The function runs only for its side effects; its result is discarded.
+    apply(func, vec![args[1].clone()])?;
+    Ok(args[1].clone())
+}
+
+// Helper function to read a repetition count, which must be a non-negative integer within the
+// maximum list size, since the results are collected into a list.
+fn extract_count(expr: &Expr, op_name: &str) -> Result<usize, LispError> {
+    match expr {
+        Expr::Number(n) if *n >= 0.0 && n.fract() == 0.0 => check_list_size(*n, op_name),
+        Expr::Number(n) => {
+            let msg = format!(
+                "{} expects a non-negative integer count, got {}",
+                op_name, n
+            );
+            error!("{}", msg);
+            Err(LispError::ValueError(msg))
+        }
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native function");
+            Err(type_error)
+        }
+    }
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_repeatedly(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'repeatedly' function");
+    if args.len() != 2 {
+        let msg = format!("repeatedly expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let count = extract_count(&args[0], "repeatedly")?;
+    let func = extract_function(&args[1], "repeatedly")?;
+
+    let results = (0..count)
+        .map(|_| apply(func.clone(), vec![]))
+        .collect::<Result<Vec<Expr>, LispError>>()?;
+    Ok(Expr::List(results))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_iterate(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'iterate' function");
+    if args.len() != 3 {
+        let msg = format!("iterate expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let func = extract_function(&args[0], "iterate")?;
+    let count = extract_count(&args[2], "iterate")?;
+
+    // The seed is the first element; each later one applies `func` to its predecessor.
+    let mut results = Vec::new();
+    let mut current = args[1].clone();
+    for i in 0..count {
+        if i > 0 {
+            current = apply(func.clone(), vec![current])?;
+        }
+        results.push(current.clone());
+    }
+    Ok(Expr::List(results))
+}
+
+// Helper for every-pred/some-pred: builds a predicate that applies each of `args` to its
+// arguments in order, stopping at the first result that settles the answer.
+fn combine_predicates(
+    args: Vec<Expr>,
+    op_name: &str,
+    require_all: bool,
+) -> Result<Expr, LispError> {
+    if args.is_empty() {
+        let msg = format!("{} expects at least 1 predicate, got 0", op_name);
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let mut preds = Vec::with_capacity(args.len());
+    for (i, arg) in args.iter().enumerate() {
+        preds.push(extract_function(
+            arg,
+            &format!("{} (arg {})", op_name, i + 1),
+        )?);
+    }
+
+    Ok(Expr::NativeClosure(NativeClosure {
+        name: op_name.to_string(),
+        func: Rc::new(move |call_args: Vec<Expr>| {
+            for pred in &preds {
+                // every-pred stops at the first falsy result, some-pred at the first truthy one.
+                if is_truthy(&apply(pred.clone(), call_args.clone())?) != require_all {
+                    return Ok(Expr::Bool(!require_all));
+                }
+            }
+            Ok(Expr::Bool(require_all))
+        }),
+    }))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_every_pred(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'every-pred' function");
+    combine_predicates(args, "every-pred", true)
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_some_pred(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'some-pred' function");
+    combine_predicates(args, "some-pred", false)
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_juxt(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'juxt' function");
+    if args.is_empty() {
+        let msg = "juxt expects at least 1 function, got 0".to_string();
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let mut funcs = Vec::with_capacity(args.len());
+    for (i, arg) in args.iter().enumerate() {
+        funcs.push(extract_function(arg, &format!("juxt (arg {})", i + 1))?);
+    }
+
+    Ok(Expr::NativeClosure(NativeClosure {
+        name: "juxt".to_string(),
+        func: Rc::new(move |call_args: Vec<Expr>| {
+            let results = funcs
+                .iter()
+                .map(|func| apply(func.clone(), call_args.clone()))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Expr::List(results))
+        }),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::eval;
+    use crate::engine::parser::parse_expr;
+    use crate::logging::init_test_logging;
+    use std::cell::Cell;
+
+    // Helper to evaluate a single Lisp expression in the given environment.
+    fn eval_function_str(code: &str, env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+        let (remaining, parsed_expr_option) = parse_expr(code)
+            .unwrap_or_else(|e| panic!("Test parse error for code '{}': {}", code, e));
+        assert!(
+            remaining.is_empty(),
+            "Unexpected remaining input after parsing in test for code '{}': {}",
+            code,
+            remaining
+        );
+        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
+        eval(&parsed_expr, env)
+    }
+
+    // Builds a native closure that counts how many times its body runs and doubles its argument.
+    fn counting_doubler(calls: Rc<Cell<usize>>) -> Expr {
+        Expr::NativeClosure(NativeClosure {
+            name: "counting-doubler".to_string(),
+            func: Rc::new(move |args: Vec<Expr>| {
+                calls.set(calls.get() + 1);
+                match &args[0] {
+                    Expr::Number(n) => Ok(Expr::Number(n * 2.0)),
+                    other => Err(LispError::TypeError {
+                        expected: "Number".to_string(),
+                        found: format!("{:?}", other),
+                    }),
+                }
+            }),
+        })
+    }
+
+    #[test]
+    fn test_native_memoize_runs_body_once_per_distinct_input() {
+        init_test_logging();
+        let calls = Rc::new(Cell::new(0));
+        let env = Environment::new_with_prelude();
+        env.borrow_mut()
+            .define("doubler".to_string(), counting_doubler(Rc::clone(&calls)));
+
+        eval_function_str("(let fast-doubler (memoize doubler))", Rc::clone(&env)).unwrap();
+
+        assert_eq!(
+            eval_function_str("(fast-doubler 21)", Rc::clone(&env)),
+            Ok(Expr::Number(42.0))
+        );
+        assert_eq!(
+            eval_function_str("(fast-doubler 21)", Rc::clone(&env)),
+            Ok(Expr::Number(42.0))
+        );
+        assert_eq!(calls.get(), 1);
+
+        assert_eq!(
+            eval_function_str("(fast-doubler 5)", Rc::clone(&env)),
+            Ok(Expr::Number(10.0))
+        );
+        assert_eq!(
+            eval_function_str("(fast-doubler 21)", Rc::clone(&env)),
+            Ok(Expr::Number(42.0))
+        );
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_native_memoize_lisp_function() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        eval_function_str("(let add (memoize (fn (a b) (+ a b))))", Rc::clone(&env)).unwrap();
+        assert_eq!(
+            eval_function_str("(add 1 2)", Rc::clone(&env)),
+            Ok(Expr::Number(3.0))
+        );
+        assert_eq!(
+            eval_function_str("(add 1 2)", Rc::clone(&env)),
+            Ok(Expr::Number(3.0))
+        );
+    }
+
+    #[test]
+    fn test_native_memoize_does_not_cache_errors() {
+        init_test_logging();
+        let calls = Rc::new(Cell::new(0));
+        let memoized = native_memoize(vec![counting_doubler(Rc::clone(&calls))]).unwrap();
+        let bad_args = vec![Expr::String("oops".to_string())];
+        assert!(apply(memoized.clone(), bad_args.clone()).is_err());
+        assert!(apply(memoized, bad_args).is_err());
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_native_memoize_type_error() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let result = eval_function_str("(memoize 42)", env);
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_native_memoize_arity_error() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let result = eval_function_str("(memoize)", env);
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_partial_add() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        eval_function_str("(let add-ten (partial + 10))", Rc::clone(&env)).unwrap();
+        assert_eq!(
+            eval_function_str("(add-ten 5)", Rc::clone(&env)),
+            Ok(Expr::Number(15.0))
+        );
+        assert_eq!(
+            eval_function_str("((partial + 10) 5)", Rc::clone(&env)),
+            Ok(Expr::Number(15.0))
+        );
+    }
+
+    #[test]
+    fn test_native_partial_preserves_argument_order() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_function_str("((partial - 10) 3)", Rc::clone(&env)),
+            Ok(Expr::Number(7.0))
+        );
+        assert_eq!(
+            eval_function_str("((partial (fn (a b c) (- a (+ b c))) 100 1) 2)", env),
+            Ok(Expr::Number(97.0))
+        );
+    }
+
+    #[test]
+    fn test_native_partial_without_captured_args() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_function_str("((partial *) 6 7)", env),
+            Ok(Expr::Number(42.0))
+        );
+    }
+
+    #[test]
+    fn test_native_partial_errors() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_function_str("(partial)", Rc::clone(&env)),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_function_str("(partial 1 2)", env),
+            Err(LispError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_native_comp_string_functions() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        eval_function_str(
+            "(let shout (comp string/to-upper string/trim))",
+            Rc::clone(&env),
+        )
+        .unwrap();
+        assert_eq!(
+            eval_function_str("(shout \"  hello  \")", Rc::clone(&env)),
+            Ok(Expr::String("HELLO".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_native_comp_applies_right_to_left() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        // (comp f g) x == (f (g x)): double first, then add one.
+        assert_eq!(
+            eval_function_str(
+                "((comp (fn (x) (+ x 1)) (fn (x) (* x 2))) 5)",
+                Rc::clone(&env)
+            ),
+            Ok(Expr::Number(11.0))
+        );
+        // The innermost function receives all call arguments.
+        assert_eq!(
+            eval_function_str("((comp - +) 1 2 3)", env),
+            Ok(Expr::Number(-6.0))
+        );
+    }
+
+    #[test]
+    fn test_native_comp_no_args_is_identity() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_function_str("((comp) \"same\")", Rc::clone(&env)),
+            Ok(Expr::String("same".to_string()))
+        );
+        assert!(matches!(
+            eval_function_str("((comp) 1 2)", env),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_comp_type_error() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_function_str("(comp string/trim 5)", env),
+            Err(LispError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_native_identity() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_function_str("(identity 42)", Rc::clone(&env)),
+            Ok(Expr::Number(42.0))
+        );
+        assert_eq!(
+            eval_function_str("(identity '(1 \"two\"))", Rc::clone(&env)),
+            Ok(Expr::List(vec![
+                Expr::Number(1.0),
+                Expr::String("two".to_string())
+            ]))
+        );
+        assert!(matches!(
+            eval_function_str("(identity 1 2)", env),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_constantly_ignores_arguments() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        eval_function_str("(let always-seven (constantly 7))", Rc::clone(&env)).unwrap();
+        assert_eq!(
+            eval_function_str("(always-seven)", Rc::clone(&env)),
+            Ok(Expr::Number(7.0))
+        );
+        assert_eq!(
+            eval_function_str("(always-seven 1)", Rc::clone(&env)),
+            Ok(Expr::Number(7.0))
+        );
+        assert_eq!(
+            eval_function_str("(always-seven \"a\" 'b '(c))", Rc::clone(&env)),
+            Ok(Expr::Number(7.0))
+        );
+        assert!(matches!(
+            eval_function_str("(constantly)", env),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_disassemble_shows_params_and_body() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        eval_function_str(
+            "(let clamp-add (fn (a b) (if (> (+ a b) 10) 10 (+ a b))))",
+            Rc::clone(&env),
+        )
+        .unwrap();
+        assert_eq!(
+            eval_function_str("(disassemble clamp-add)", Rc::clone(&env)),
+            Ok(Expr::String(
+                "(fn (a b) (if (> (+ a b) 10) 10 (+ a b)))".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_native_disassemble_errors() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_function_str("(disassemble +)", Rc::clone(&env)),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_function_str("(disassemble 1)", Rc::clone(&env)),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_function_str("(disassemble)", env),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_tap_runs_side_effect_and_returns_value() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let recorder = Rc::clone(&seen);
+        env.borrow_mut().define(
+            "record".to_string(),
+            Expr::NativeClosure(NativeClosure {
+                name: "record".to_string(),
+                func: Rc::new(move |call_args: Vec<Expr>| {
+                    recorder.borrow_mut().extend(call_args);
+                    Ok(Expr::String("ignored".to_string()))
+                }),
+            }),
+        );
+
+        assert_eq!(
+            eval_function_str("(tap record '(1 2))", Rc::clone(&env)),
+            Ok(Expr::List(vec![Expr::Number(1.0), Expr::Number(2.0)]))
+        );
+        assert_eq!(
+            eval_function_str("(+ 1 (tap record 41))", Rc::clone(&env)),
+            Ok(Expr::Number(42.0))
+        );
+        assert_eq!(
+            *seen.borrow(),
+            vec![
+                Expr::List(vec![Expr::Number(1.0), Expr::Number(2.0)]),
+                Expr::Number(41.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_native_tap_errors() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_function_str("(tap 1 2)", Rc::clone(&env)),
+            Err(LispError::TypeError { .. })
+        ));
+        // Errors from the tapped function propagate.
+        assert!(matches!(
+            eval_function_str("(tap (fn (x) (+ x \"a\")) 1)", Rc::clone(&env)),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_function_str("(tap identity)", env),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_repeatedly_collects_thunk_results() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let counter = Rc::new(RefCell::new(0.0));
+        let next_counter = Rc::clone(&counter);
+        env.borrow_mut().define(
+            "next-id".to_string(),
+            Expr::NativeClosure(NativeClosure {
+                name: "next-id".to_string(),
+                func: Rc::new(move |_call_args: Vec<Expr>| {
+                    *next_counter.borrow_mut() += 1.0;
+                    Ok(Expr::Number(*next_counter.borrow()))
+                }),
+            }),
+        );
+
+        assert_eq!(
+            eval_function_str("(repeatedly 3 next-id)", Rc::clone(&env)),
+            Ok(Expr::List(vec![
+                Expr::Number(1.0),
+                Expr::Number(2.0),
+                Expr::Number(3.0)
+            ]))
+        );
+        assert_eq!(
+            eval_function_str("(repeatedly 0 next-id)", Rc::clone(&env)),
+            Ok(Expr::List(vec![]))
+        );
+        assert_eq!(*counter.borrow(), 3.0);
+    }
+
+    #[test]
+    fn test_native_repeatedly_errors() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_function_str("(repeatedly -1 (fn () 1))", Rc::clone(&env)),
+            Err(LispError::ValueError(_))
+        ));
+        assert!(matches!(
+            eval_function_str("(repeatedly 2 5)", Rc::clone(&env)),
+            Err(LispError::TypeError { .. })
+        ));
+        // The thunk is called with no arguments.
+        assert!(matches!(
+            eval_function_str("(repeatedly 2 (fn (x) x))", Rc::clone(&env)),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_function_str("(repeatedly 2)", Rc::clone(&env)),
+            Err(LispError::ArityMismatch(_))
+        ));
+        // Counts beyond the maximum list size fail before the thunk is ever called.
+        assert!(matches!(
+            eval_function_str("(repeatedly 1e18 (fn () 1))", env),
+            Err(LispError::ValueError(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_iterate_doubling() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_function_str("(iterate (fn (x) (* x 2)) 1 5)", Rc::clone(&env)),
+            Ok(Expr::List(vec![
+                Expr::Number(1.0),
+                Expr::Number(2.0),
+                Expr::Number(4.0),
+                Expr::Number(8.0),
+                Expr::Number(16.0)
+            ]))
+        );
+        assert_eq!(
+            eval_function_str("(iterate (fn (x) (* x 2)) 1 1)", Rc::clone(&env)),
+            Ok(Expr::List(vec![Expr::Number(1.0)]))
+        );
+        assert_eq!(
+            eval_function_str("(iterate (fn (x) (* x 2)) 1 0)", env),
+            Ok(Expr::List(vec![]))
+        );
+    }
+
+    #[test]
+    fn test_native_iterate_errors() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_function_str("(iterate (fn (x) x) 1 2.5)", Rc::clone(&env)),
+            Err(LispError::ValueError(_))
+        ));
+        assert!(matches!(
+            eval_function_str("(iterate 1 1 3)", Rc::clone(&env)),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_function_str("(iterate (fn (x) x) 1)", Rc::clone(&env)),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_function_str("(iterate (fn (x) x) 1 1e18)", env),
+            Err(LispError::ValueError(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_every_pred_and_some_pred() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        eval_function_str("(let positive? (fn (x) (> x 0)))", Rc::clone(&env)).unwrap();
+        eval_function_str("(let small? (fn (x) (< x 10)))", Rc::clone(&env)).unwrap();
+        eval_function_str(
+            "(let in-range? (every-pred positive? small?))",
+            Rc::clone(&env),
+        )
+        .unwrap();
+        eval_function_str(
+            "(let either? (some-pred positive? small?))",
+            Rc::clone(&env),
+        )
+        .unwrap();
+
+        // Passes all, some, and none of the predicates.
+        for (value, all, any) in [("5", true, true), ("50", false, true)] {
+            assert_eq!(
+                eval_function_str(&format!("(in-range? {})", value), Rc::clone(&env)),
+                Ok(Expr::Bool(all))
+            );
+            assert_eq!(
+                eval_function_str(&format!("(either? {})", value), Rc::clone(&env)),
+                Ok(Expr::Bool(any))
+            );
+        }
+        eval_function_str("(let big? (fn (x) (> x 100)))", Rc::clone(&env)).unwrap();
+        assert_eq!(
+            eval_function_str("((some-pred big? (fn (x) (< x -100))) 5)", Rc::clone(&env)),
+            Ok(Expr::Bool(false))
+        );
+        assert_eq!(
+            eval_function_str("((every-pred big? positive?) 5)", env),
+            Ok(Expr::Bool(false))
+        );
+    }
+
+    #[test]
+    fn test_native_every_pred_and_some_pred_short_circuit() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        // The second predicate would fail on a string, so it must never run.
+        assert_eq!(
+            eval_function_str(
+                "((every-pred (fn (x) false) (fn (x) (> x 0))) \"a\")",
+                Rc::clone(&env)
+            ),
+            Ok(Expr::Bool(false))
+        );
+        assert_eq!(
+            eval_function_str("((some-pred (fn (x) true) (fn (x) (> x 0))) \"a\")", env),
+            Ok(Expr::Bool(true))
+        );
+    }
+
+    #[test]
+    fn test_native_every_pred_errors() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_function_str("(every-pred)", Rc::clone(&env)),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_function_str("(some-pred 1)", env),
+            Err(LispError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_native_juxt() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_function_str("((juxt list/car list/last) '(1 2 3))", Rc::clone(&env)),
+            Ok(Expr::List(vec![Expr::Number(1.0), Expr::Number(3.0)]))
+        );
+        // Every function receives all of the call arguments.
+        assert_eq!(
+            eval_function_str("((juxt + - (fn (a b) b)) 5 2)", Rc::clone(&env)),
+            Ok(Expr::List(vec![
+                Expr::Number(7.0),
+                Expr::Number(3.0),
+                Expr::Number(2.0)
+            ]))
+        );
+        assert!(matches!(
+            eval_function_str("(juxt)", Rc::clone(&env)),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_function_str("(juxt list/car 1)", env),
+            Err(LispError::TypeError { .. })
+        ));
+    }
+}
@@ -1,16 +1,38 @@
 use crate::engine::ast::{Expr, NativeFunction};
+use crate::engine::builtins::csv::create_csv_module;
+use crate::engine::builtins::function::{
+    native_comp, native_constantly, native_disassemble, native_every_pred, native_identity,
+    native_iterate, native_juxt, native_memoize, native_partial, native_repeatedly,
+    native_some_pred, native_tap,
+};
+use crate::engine::builtins::json::create_json_module;
+use crate::engine::builtins::list::create_list_module;
 use crate::engine::builtins::log::create_log_module;
+use crate::engine::builtins::map::create_map_module;
 use crate::engine::builtins::math::{
     create_math_module, native_add, native_divide, native_equals, native_greater_than,
     native_greater_than_or_equal, native_less_than, native_less_than_or_equal, native_multiply,
     native_subtract,
 };
-use crate::engine::builtins::list::create_list_module;
 use crate::engine::builtins::string::create_string_module;
+use crate::engine::builtins::system::{
+    native_debug, native_env_get, native_env_set, native_merge_modules, native_module_members,
+    native_modules, native_read_line, native_rsp_version, native_sleep,
+};
+use crate::engine::builtins::value::{
+    native_boolean, native_deep_copy, native_hash, native_length, native_not,
+    native_number_to_string,
+};
+use crate::engine::builtins::vector::create_vector_module;
 use crate::engine::env::Environment;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Names of the built-in modules that `populate_globals` binds in the prelude.
+pub const BUILTIN_MODULES: &[&str] = &[
+    "math", "log", "string", "list", "map", "json", "csv", "vector",
+];
+
 /// Populates the given environment with global built-in modules and functions.
 pub fn populate_globals(env: Rc<RefCell<Environment>>) {
     // Create the math module using its dedicated function
@@ -25,12 +47,28 @@ pub fn populate_globals(env: Rc<RefCell<Environment>>) {
     // Create the list module using its dedicated function
     let list_module = create_list_module();
 
+    // Create the map module using its dedicated function
+    let map_module = create_map_module();
+
+    // Create the json module using its dedicated function
+    let json_module = create_json_module();
+
+    // Create the csv module using its dedicated function
+    let csv_module = create_csv_module();
+
+    // Create the vector module using its dedicated function
+    let vector_module = create_vector_module();
+
     // Define functions and modules in the root prelude
     let mut root_env_borrowed = env.borrow_mut();
     root_env_borrowed.define("math".to_string(), math_module);
     root_env_borrowed.define("log".to_string(), log_module);
     root_env_borrowed.define("string".to_string(), string_module);
     root_env_borrowed.define("list".to_string(), list_module);
+    root_env_borrowed.define("map".to_string(), map_module);
+    root_env_borrowed.define("json".to_string(), json_module);
+    root_env_borrowed.define("csv".to_string(), csv_module);
+    root_env_borrowed.define("vector".to_string(), vector_module);
 
     // Define shorthand math functions directly in root prelude
     root_env_borrowed.define(
@@ -96,4 +134,199 @@ pub fn populate_globals(env: Rc<RefCell<Environment>>) {
             func: native_greater_than_or_equal,
         }),
     );
+
+    // Define system-level helpers directly in root prelude
+    root_env_borrowed.define(
+        "sleep".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "sleep".to_string(),
+            func: native_sleep,
+        }),
+    );
+    root_env_borrowed.define(
+        "rsp-version".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "rsp-version".to_string(),
+            func: native_rsp_version,
+        }),
+    );
+    root_env_borrowed.define(
+        "read-line".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "read-line".to_string(),
+            func: native_read_line,
+        }),
+    );
+    root_env_borrowed.define(
+        "modules".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "modules".to_string(),
+            func: native_modules,
+        }),
+    );
+    root_env_borrowed.define(
+        "merge-modules".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "merge-modules".to_string(),
+            func: native_merge_modules,
+        }),
+    );
+    root_env_borrowed.define(
+        "module-members".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "module-members".to_string(),
+            func: native_module_members,
+        }),
+    );
+    root_env_borrowed.define(
+        "env-get".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "env-get".to_string(),
+            func: native_env_get,
+        }),
+    );
+    root_env_borrowed.define(
+        "env-set".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "env-set".to_string(),
+            func: native_env_set,
+        }),
+    );
+    root_env_borrowed.define(
+        "debug".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "debug".to_string(),
+            func: native_debug,
+        }),
+    );
+
+    // Define higher-order function helpers directly in root prelude
+    root_env_borrowed.define(
+        "identity".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "identity".to_string(),
+            func: native_identity,
+        }),
+    );
+    root_env_borrowed.define(
+        "constantly".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "constantly".to_string(),
+            func: native_constantly,
+        }),
+    );
+    root_env_borrowed.define(
+        "memoize".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "memoize".to_string(),
+            func: native_memoize,
+        }),
+    );
+    root_env_borrowed.define(
+        "partial".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "partial".to_string(),
+            func: native_partial,
+        }),
+    );
+    root_env_borrowed.define(
+        "comp".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "comp".to_string(),
+            func: native_comp,
+        }),
+    );
+    root_env_borrowed.define(
+        "disassemble".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "disassemble".to_string(),
+            func: native_disassemble,
+        }),
+    );
+    root_env_borrowed.define(
+        "tap".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "tap".to_string(),
+            func: native_tap,
+        }),
+    );
+    root_env_borrowed.define(
+        "repeatedly".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "repeatedly".to_string(),
+            func: native_repeatedly,
+        }),
+    );
+    root_env_borrowed.define(
+        "iterate".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "iterate".to_string(),
+            func: native_iterate,
+        }),
+    );
+    root_env_borrowed.define(
+        "every-pred".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "every-pred".to_string(),
+            func: native_every_pred,
+        }),
+    );
+    root_env_borrowed.define(
+        "some-pred".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "some-pred".to_string(),
+            func: native_some_pred,
+        }),
+    );
+    root_env_borrowed.define(
+        "juxt".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "juxt".to_string(),
+            func: native_juxt,
+        }),
+    );
+
+    // Define generic value helpers directly in root prelude
+    root_env_borrowed.define(
+        "length".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "length".to_string(),
+            func: native_length,
+        }),
+    );
+    root_env_borrowed.define(
+        "boolean".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "boolean".to_string(),
+            func: native_boolean,
+        }),
+    );
+    root_env_borrowed.define(
+        "not".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "not".to_string(),
+            func: native_not,
+        }),
+    );
+    root_env_borrowed.define(
+        "hash".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "hash".to_string(),
+            func: native_hash,
+        }),
+    );
+    root_env_borrowed.define(
+        "deep-copy".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "deep-copy".to_string(),
+            func: native_deep_copy,
+        }),
+    );
+    root_env_borrowed.define(
+        "number->string".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "number->string".to_string(),
+            func: native_number_to_string,
+        }),
+    );
 }
@@ -0,0 +1,243 @@
+//! The `json` module: serializing values as JSON text.
+//!
+//! Values map onto JSON as follows: numbers (and rationals) become JSON numbers, strings and
+//! symbols become JSON strings, booleans stay booleans and `nil` becomes `null`. A non-empty
+//! list whose elements are all `(key value)` entries with string or symbol keys is treated
+//! as a map and becomes an object; any other list becomes an array.
+
+use crate::engine::ast::{Expr, LispModule, NativeFunction};
+use crate::engine::env::Environment;
+use crate::engine::eval::LispError;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::{error, trace};
+
+// Spaces added per nesting level by json/pretty.
+const PRETTY_INDENT: usize = 2;
+
+// Helper: returns the object entries of `items` if the list has the shape of a map.
+fn object_entries(items: &[Expr]) -> Option<Vec<(String, &Expr)>> {
+    if items.is_empty() {
+        return None;
+    }
+    items
+        .iter()
+        .map(|item| match item {
+            Expr::List(pair) if pair.len() == 2 => match &pair[0] {
+                Expr::String(key) | Expr::Symbol(key) => Some((key.clone(), &pair[1])),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+// Helper: appends `s` as a quoted JSON string, escaping quotes, backslashes and control
+// characters.
+fn write_json_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// Helper: appends the JSON number for `n`. JSON has no representation for NaN or infinities.
+fn write_json_number(n: f64, out: &mut String, op_name: &str) -> Result<(), LispError> {
+    if !n.is_finite() {
+        let msg = format!("{} cannot represent {} as a JSON number", op_name, n);
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    out.push_str(&n.to_string());
+    Ok(())
+}
+
+// Helper: appends `expr` as indented JSON, with nested lines indented one level past `depth`.
+fn write_pretty(expr: &Expr, depth: usize, out: &mut String) -> Result<(), LispError> {
+    let op_name = "json/pretty";
+    match expr {
+        Expr::Number(n) => write_json_number(*n, out, op_name)?,
+        Expr::Rational { num, den } => write_json_number(*num as f64 / *den as f64, out, op_name)?,
+        Expr::String(s) | Expr::Symbol(s) => write_json_string(s, out),
+        Expr::Bool(b) => out.push_str(&b.to_string()),
+        Expr::Nil => out.push_str("null"),
+        Expr::List(items) if items.is_empty() => out.push_str("[]"),
+        Expr::List(items) => {
+            let inner = " ".repeat((depth + 1) * PRETTY_INDENT);
+            let entries = object_entries(items);
+            let (open, close) = if entries.is_some() {
+                ('{', '}')
+            } else {
+                ('[', ']')
+            };
+            out.push(open);
+            match entries {
+                Some(entries) => {
+                    for (i, (key, value)) in entries.into_iter().enumerate() {
+                        out.push_str(if i == 0 { "\n" } else { ",\n" });
+                        out.push_str(&inner);
+                        write_json_string(&key, out);
+                        out.push_str(": ");
+                        write_pretty(value, depth + 1, out)?;
+                    }
+                }
+                None => {
+                    for (i, item) in items.iter().enumerate() {
+                        out.push_str(if i == 0 { "\n" } else { ",\n" });
+                        out.push_str(&inner);
+                        write_pretty(item, depth + 1, out)?;
+                    }
+                }
+            }
+            out.push('\n');
+            out.push_str(&" ".repeat(depth * PRETTY_INDENT));
+            out.push(close);
+        }
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "JSON-compatible value".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native json function");
+            return Err(type_error);
+        }
+    }
+    Ok(())
+}
+
+// Native function for indented JSON output: (json/pretty x)
+fn native_json_pretty(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native json function: json/pretty");
+    if args.len() != 1 {
+        let msg = format!("json/pretty expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let mut out = String::new();
+    write_pretty(&args[0], 0, &mut out)?;
+    Ok(Expr::String(out))
+}
+
+/// Creates the `json` module with its associated functions.
+pub fn create_json_module() -> Expr {
+    trace!("Creating json module");
+    let json_env_rc = Environment::new(); // Modules have their own environment
+
+    // Scope the mutable borrow so it's dropped before json_env_rc is moved
+    {
+        let mut json_env_borrowed = json_env_rc.borrow_mut();
+        let functions_to_define: HashMap<String, Expr> = HashMap::from([(
+            "pretty".to_string(),
+            Expr::NativeFunction(NativeFunction {
+                name: "json/pretty".to_string(),
+                func: native_json_pretty,
+            }),
+        )]);
+
+        for (name, func_expr) in functions_to_define {
+            json_env_borrowed.define(name, func_expr);
+        }
+    }
+
+    Expr::Module(LispModule {
+        path: PathBuf::from("builtin:json"),
+        env: json_env_rc,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::eval::eval;
+    use crate::engine::parser::parse_expr;
+    use crate::logging::init_test_logging;
+
+    // Helper to evaluate a Lisp string in an environment that includes the json module.
+    fn eval_json_str(code: &str) -> Result<Expr, LispError> {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let (remaining, parsed_expr_option) = parse_expr(code)
+            .unwrap_or_else(|e| panic!("Test parse error for code '{}': {}", code, e));
+        assert!(
+            remaining.is_empty(),
+            "Unexpected remaining input after parsing in test for code '{}': {}",
+            code,
+            remaining
+        );
+        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
+        eval(&parsed_expr, env)
+    }
+
+    // Tests for json/pretty
+    #[test]
+    fn test_native_json_pretty_nested_structure() {
+        let result = eval_json_str(
+            r#"(json/pretty '((name "rsp") (tags ("lisp" "rust")) (meta ((stable false) (score 1.5) (parent nil)))))"#,
+        );
+        let expected = r#"{
+  "name": "rsp",
+  "tags": [
+    "lisp",
+    "rust"
+  ],
+  "meta": {
+    "stable": false,
+    "score": 1.5,
+    "parent": null
+  }
+}"#;
+        assert_eq!(result, Ok(Expr::String(expected.to_string())));
+    }
+
+    #[test]
+    fn test_native_json_pretty_scalars_and_empty_list() {
+        assert_eq!(
+            eval_json_str("(json/pretty 42)"),
+            Ok(Expr::String("42".to_string()))
+        );
+        assert_eq!(
+            eval_json_str("(json/pretty 1/4)"),
+            Ok(Expr::String("0.25".to_string()))
+        );
+        assert_eq!(
+            eval_json_str(r#"(json/pretty "say \"hi\"\n")"#),
+            Ok(Expr::String(r#""say \"hi\"\n""#.to_string()))
+        );
+        assert_eq!(
+            eval_json_str("(json/pretty '())"),
+            Ok(Expr::String("[]".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_native_json_pretty_array_of_numbers() {
+        assert_eq!(
+            eval_json_str("(json/pretty '(1 (2 3)))"),
+            Ok(Expr::String(
+                "[\n  1,\n  [\n    2,\n    3\n  ]\n]".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_native_json_pretty_errors() {
+        let result_fn = eval_json_str("(json/pretty (fn (x) x))");
+        assert!(matches!(result_fn, Err(LispError::TypeError { .. })));
+
+        let result_nan = eval_json_str("(json/pretty nan)");
+        assert!(matches!(result_nan, Err(LispError::ValueError(_))));
+
+        let result_arity = eval_json_str("(json/pretty)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+}
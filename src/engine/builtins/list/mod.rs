@@ -1,9 +1,29 @@
-use crate::engine::ast::{Expr, NativeFunction};
+use crate::engine::ast::{Expr, LispModule, NativeFunction};
+use crate::engine::builtins::value::hash_value;
 use crate::engine::env::Environment;
-use crate::engine::eval::LispError;
+use crate::engine::eval::{LispError, apply, is_truthy};
+use std::cell::Cell;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use tracing::{error, trace};
 
+// Helper function to borrow the elements of a list argument; nil is treated as the empty list.
+fn extract_list<'a>(expr: &'a Expr, op_name: &str) -> Result<&'a [Expr], LispError> {
+    match expr {
+        Expr::List(list) => Ok(list.as_slice()),
+        Expr::Nil => Ok(&[]),
+        _ => {
+            let type_error = LispError::TypeError {
+                expected: "List".to_string(),
+                found: format!("{:?}", expr),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native list function");
+            Err(type_error)
+        }
+    }
+}
+
 fn native_list_length(args: Vec<Expr>) -> Result<Expr, LispError> {
     trace!("Executing native list function: list/length");
     if args.len() != 1 {
@@ -131,295 +151,2172 @@ fn native_list_last(args: Vec<Expr>) -> Result<Expr, LispError> {
     }
 }
 
-/// Creates the `list` module with its associated functions.
-pub fn create_list_module() -> Expr {
-    trace!("Creating list module");
-    let list_env_rc = Environment::new(); // Modules have their own environment
+fn native_list_zip_with(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/zip-with");
+    if args.len() != 3 {
+        let msg = format!("list/zip-with expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
 
-    // Scope the mutable borrow so it's dropped before list_env_rc is moved
-    {
-        let mut list_env_borrowed = list_env_rc.borrow_mut();
-        let functions_to_define: HashMap<String, Expr> = HashMap::from([
-            (
-                "length".to_string(),
-                Expr::NativeFunction(NativeFunction {
-                    name: "list/length".to_string(),
-                    func: native_list_length,
-                }),
-            ),
-            (
-                "car".to_string(),
-                Expr::NativeFunction(NativeFunction {
-                    name: "list/car".to_string(),
-                    func: native_list_car,
-                }),
-            ),
-            (
-                "cdr".to_string(),
-                Expr::NativeFunction(NativeFunction {
-                    name: "list/cdr".to_string(),
-                    func: native_list_cdr,
-                }),
-            ),
-            (
-                "last".to_string(),
-                Expr::NativeFunction(NativeFunction {
-                    name: "list/last".to_string(),
-                    func: native_list_last,
-                }),
-            ),
-        ]);
+    let func = args[0].clone();
+    let left = extract_list(&args[1], "list/zip-with")?;
+    let right = extract_list(&args[2], "list/zip-with")?;
 
-        for (name, func_expr) in functions_to_define {
-            list_env_borrowed.define(name, func_expr);
-        }
+    // zip truncates to the shorter of the two lists.
+    let mut results = Vec::with_capacity(left.len().min(right.len()));
+    for (a, b) in left.iter().zip(right.iter()) {
+        results.push(apply(func.clone(), vec![a.clone(), b.clone()])?);
     }
-
-    Expr::Module(crate::engine::ast::LispModule {
-        // Using a temporary path, or deciding on a convention for "virtual" modules
-        path: std::path::PathBuf::from("<builtin_list_module>"),
-        env: list_env_rc,
-    })
+    Ok(Expr::List(results))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::engine::ast::Expr;
-    use crate::engine::env::Environment;
-    use crate::engine::eval::{eval, LispError};
-    use crate::engine::parser::parse_expr;
-    use crate::logging::init_test_logging;
-    // Removed unused imports for RefCell and Rc for the test module
+fn native_list_flat_map(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/flat-map");
+    if args.len() != 2 {
+        let msg = format!("list/flat-map expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
 
-    // Helper to evaluate a Lisp string in an environment that includes the list module.
-    fn eval_list_str(code: &str) -> Result<Expr, LispError> {
-        init_test_logging();
-        let env = Environment::new_with_prelude(); // Includes math, log, string, list
-        let parse_result = parse_expr(code);
-        let (remaining, parsed_expr_option) = match parse_result {
-            Ok((rem, expr_opt)) => (rem, expr_opt),
-            Err(e) => panic!("Test parse error for code '{}': {}", code, e),
-        };
+    let func = args[0].clone();
+    let items = extract_list(&args[1], "list/flat-map")?;
 
-        if !remaining.is_empty() {
-            panic!(
-                "Unexpected remaining input after parsing in test for code '{}': {}",
-                code, remaining
-            );
-        }
-        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
-        eval(&parsed_expr, env)
+    let mut results = Vec::new();
+    for item in items {
+        let mapped = apply(func.clone(), vec![item.clone()])?;
+        // Each result must itself be a list (or nil), whose elements are spliced in.
+        results.extend_from_slice(extract_list(&mapped, "list/flat-map")?);
     }
+    Ok(Expr::List(results))
+}
 
-    #[test]
-    fn test_native_list_length_empty_list() {
-        let result = eval_list_str("(list/length '())").unwrap();
-        assert_eq!(result, Expr::Number(0.0));
+fn native_list_repeat(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/repeat");
+    if args.len() != 2 {
+        let msg = format!("list/repeat expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
     }
 
-    #[test]
-    fn test_native_list_length_nil_direct() {
-        // Note: `nil` itself is not directly callable as a list function argument
-        // in this manner without `quote`. We test `list/length` with `nil` argument.
-        // The function `native_list_length` handles `Expr::Nil` directly.
-        // The `env` variable was unused here as native_list_length doesn't need it for this direct call.
-        init_test_logging(); // Ensure logging is initialized for direct native function tests too
-        let result = native_list_length(vec![Expr::Nil]).unwrap();
-        assert_eq!(result, Expr::Number(0.0));
+    let count = match &args[0] {
+        Expr::Number(n) => *n,
+        other => {
+            let msg = format!("list/repeat expects a number as count, got {:?}", other);
+            error!("{}", msg);
+            return Err(LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            });
+        }
+    };
+    if count < 0.0 || count.fract() != 0.0 || !count.is_finite() {
+        let msg = format!(
+            "list/repeat expects a non-negative integer count, got {}",
+            count
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
     }
 
-    #[test]
-    fn test_native_list_length_non_empty_list() {
-        let result = eval_list_str("(list/length '(1 2 3))").unwrap();
-        assert_eq!(result, Expr::Number(3.0));
+    let count = check_list_size(count, "list/repeat")?;
+    Ok(Expr::List(vec![args[1].clone(); count]))
+}
 
-        let result_nested = eval_list_str("(list/length '(1 (2 3) 4))").unwrap();
-        assert_eq!(result_nested, Expr::Number(3.0));
+fn native_list_interpose(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/interpose");
+    if args.len() != 2 {
+        let msg = format!("list/interpose expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
     }
 
-    #[test]
-    fn test_native_list_length_arity_error_no_args() {
-        let result = eval_list_str("(list/length)");
-        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    let separator = &args[0];
+    let items = extract_list(&args[1], "list/interpose")?;
+    if items.len() < 2 {
+        // Nothing to separate; hand back the original list (or nil) as-is.
+        return Ok(args[1].clone());
     }
 
-    #[test]
-    fn test_native_list_length_arity_error_too_many_args() {
-        let result = eval_list_str("(list/length '(1) '(2))");
-        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    let mut results = Vec::with_capacity(items.len() * 2 - 1);
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            results.push(separator.clone());
+        }
+        results.push(item.clone());
     }
+    Ok(Expr::List(results))
+}
 
-    #[test]
-    fn test_native_list_length_type_error_not_a_list() {
-        let result = eval_list_str("(list/length 123)");
-        assert!(matches!(result, Err(LispError::TypeError { .. })));
-
-        let result_string = eval_list_str("(list/length \"hello\")");
-        assert!(matches!(result_string, Err(LispError::TypeError { .. })));
+fn native_list_frequencies(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/frequencies");
+    if args.len() != 1 {
+        let msg = format!("list/frequencies expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
     }
 
-    // Tests for list/car
-    #[test]
-    fn test_native_list_car_simple() {
-        let result = eval_list_str("(list/car '(1 2 3))").unwrap();
-        assert_eq!(result, Expr::Number(1.0));
-    }
+    let items = extract_list(&args[0], "list/frequencies")?;
 
-    #[test]
-    fn test_native_list_car_single_element_list() {
-        let result = eval_list_str("(list/car '(a))").unwrap();
-        assert_eq!(result, Expr::Symbol("a".to_string()));
+    // Entries keep the order in which each distinct element first appears; the map buckets
+    // entry positions by the element's hash.
+    let mut counts: Vec<(Expr, usize)> = Vec::new();
+    let mut positions: HashMap<u64, Vec<usize>> = HashMap::new();
+    for item in items {
+        let bucket = positions.entry(hash_value(item)).or_default();
+        match bucket.iter().find(|&&position| counts[position].0 == *item) {
+            Some(&position) => counts[position].1 += 1,
+            None => {
+                bucket.push(counts.len());
+                counts.push((item.clone(), 1));
+            }
+        }
     }
 
-    #[test]
-    fn test_native_list_car_nested_list() {
-        let result = eval_list_str("(list/car '((1 2) 3))").unwrap();
-        assert_eq!(
-            result,
-            Expr::List(vec![Expr::Number(1.0), Expr::Number(2.0)])
+    // Returned as an association list: ((element count) ...)
+    Ok(Expr::List(
+        counts
+            .into_iter()
+            .map(|(item, count)| Expr::List(vec![item, Expr::Number(count as f64)]))
+            .collect(),
+    ))
+}
+
+// Helper to check that sort keys are all numbers or all strings, so they can be ordered.
+fn validate_sort_keys(keys: &[Expr], op_name: &str) -> Result<(), LispError> {
+    let Some(first) = keys.first() else {
+        return Ok(());
+    };
+    for key in keys {
+        let comparable = matches!(
+            (first, key),
+            (Expr::Number(_), Expr::Number(_)) | (Expr::String(_), Expr::String(_))
         );
+        if !comparable {
+            let type_error = LispError::TypeError {
+                expected: match first {
+                    Expr::String(_) => "String".to_string(),
+                    _ => "Number".to_string(),
+                },
+                found: format!("{:?}", key),
+            };
+            error!(operator = %op_name, error = %type_error, "Sort keys must be all numbers or all strings");
+            return Err(type_error);
+        }
     }
+    Ok(())
+}
 
-    #[test]
-    fn test_native_list_car_empty_list_error() {
-        let result = eval_list_str("(list/car '())");
-        assert!(matches!(result, Err(LispError::ValueError(_))));
+// Orders two sort keys that have already been checked by `validate_sort_keys`.
+fn compare_sort_keys(a: &Expr, b: &Expr) -> Ordering {
+    match (a, b) {
+        (Expr::Number(x), Expr::Number(y)) => x.total_cmp(y),
+        (Expr::String(x), Expr::String(y)) => x.cmp(y),
+        _ => Ordering::Equal,
     }
+}
 
-    #[test]
-    fn test_native_list_car_nil_error() {
-        // Evaluating (list/car nil) would first try to evaluate nil as a variable.
-        // To test car with Expr::Nil directly:
-        let result = native_list_car(vec![Expr::Nil]);
-        assert!(matches!(result, Err(LispError::ValueError(_))));
+fn native_list_sort_by(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/sort-by");
+    if args.len() != 2 {
+        let msg = format!("list/sort-by expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
     }
 
-    #[test]
-    fn test_native_list_car_type_error() {
-        let result = eval_list_str("(list/car 123)");
-        assert!(matches!(result, Err(LispError::TypeError { .. })));
-    }
+    let key_fn = args[0].clone();
+    let items = extract_list(&args[1], "list/sort-by")?;
 
-    #[test]
-    fn test_native_list_car_arity_error() {
-        let result_no_args = eval_list_str("(list/car)");
-        assert!(matches!(result_no_args, Err(LispError::ArityMismatch(_))));
+    // Compute each key once up front rather than on every comparison.
+    let keys = items
+        .iter()
+        .map(|item| apply(key_fn.clone(), vec![item.clone()]))
+        .collect::<Result<Vec<Expr>, LispError>>()?;
+    validate_sort_keys(&keys, "list/sort-by")?;
 
-        let result_too_many = eval_list_str("(list/car '(1) '(2))");
-        assert!(matches!(
-            result_too_many,
-            Err(LispError::ArityMismatch(_))
-        ));
+    let mut keyed: Vec<(Expr, Expr)> = keys.into_iter().zip(items.iter().cloned()).collect();
+    // sort_by is stable, so elements with equal keys keep their original order.
+    keyed.sort_by(|(a, _), (b, _)| compare_sort_keys(a, b));
+    Ok(Expr::List(
+        keyed.into_iter().map(|(_, item)| item).collect(),
+    ))
+}
+
+fn native_list_reductions(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/reductions");
+    if args.len() != 3 {
+        let msg = format!("list/reductions expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
     }
 
-    // Tests for list/cdr
-    #[test]
-    fn test_native_list_cdr_simple() {
-        let result = eval_list_str("(list/cdr '(1 2 3))").unwrap();
-        assert_eq!(
-            result,
-            Expr::List(vec![Expr::Number(2.0), Expr::Number(3.0)])
-        );
+    let func = args[0].clone();
+    let items = extract_list(&args[2], "list/reductions")?;
+
+    // Every intermediate accumulator, starting with the initial value.
+    let mut results = Vec::with_capacity(items.len() + 1);
+    let mut acc = args[1].clone();
+    results.push(acc.clone());
+    for item in items {
+        acc = apply(func.clone(), vec![acc, item.clone()])?;
+        results.push(acc.clone());
     }
+    Ok(Expr::List(results))
+}
 
-    #[test]
-    fn test_native_list_cdr_single_element_list() {
-        let result = eval_list_str("(list/cdr '(a))").unwrap();
-        assert_eq!(result, Expr::List(vec![])); // cdr of a single element list is an empty list
+fn native_list_nth(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/nth");
+    if args.len() != 2 {
+        let msg = format!("list/nth expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
     }
 
-    #[test]
-    fn test_native_list_cdr_nested_list() {
-        let result = eval_list_str("(list/cdr '((1 2) (3 4) 5))").unwrap();
-        assert_eq!(
-            result,
-            Expr::List(vec![
-                Expr::List(vec![Expr::Number(3.0), Expr::Number(4.0)]),
-                Expr::Number(5.0)
-            ])
+    let items = extract_list(&args[0], "list/nth")?;
+    let index = match &args[1] {
+        Expr::Number(n) if n.fract() == 0.0 => *n,
+        Expr::Number(n) => {
+            let msg = format!("list/nth expects an integer index, got {}", n);
+            error!("{}", msg);
+            return Err(LispError::ValueError(msg));
+        }
+        other => {
+            let msg = format!("list/nth expects a number as index, got {:?}", other);
+            error!("{}", msg);
+            return Err(LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            });
+        }
+    };
+
+    // Negative indices count from the end: -1 is the last element.
+    let len = items.len() as f64;
+    let resolved = if index < 0.0 { len + index } else { index };
+    if resolved < 0.0 || resolved >= len {
+        let msg = format!(
+            "list/nth index {} is out of range for a list of length {}",
+            index,
+            items.len()
         );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
     }
+    Ok(items[resolved as usize].clone())
+}
 
-    #[test]
-    fn test_native_list_cdr_empty_list_error() {
-        let result = eval_list_str("(list/cdr '())");
-        assert!(matches!(result, Err(LispError::ValueError(_))));
+fn native_list_second_to_last(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/second-to-last");
+    if args.len() != 1 {
+        let msg = format!("list/second-to-last expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
     }
 
-    #[test]
-    fn test_native_list_cdr_nil_error() {
-        let result = native_list_cdr(vec![Expr::Nil]);
-        assert!(matches!(result, Err(LispError::ValueError(_))));
+    // Same as (list/nth lst -2); like list/last, a list that is too short is an error.
+    native_list_nth(vec![args[0].clone(), Expr::Number(-2.0)])
+}
+
+// Helper function to read a strictly positive integer size argument (chunk/window sizes).
+fn extract_positive_size(expr: &Expr, op_name: &str) -> Result<usize, LispError> {
+    let n = match expr {
+        Expr::Number(n) => *n,
+        other => {
+            let msg = format!("{} expects a number as size, got {:?}", op_name, other);
+            error!("{}", msg);
+            return Err(LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            });
+        }
+    };
+    if n < 1.0 || n.fract() != 0.0 || !n.is_finite() {
+        let msg = format!("{} expects a positive integer size, got {}", op_name, n);
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
     }
+    Ok(n as usize)
+}
 
-    #[test]
-    fn test_native_list_cdr_type_error() {
-        let result = eval_list_str("(list/cdr 123)");
-        assert!(matches!(result, Err(LispError::TypeError { .. })));
+fn native_list_chunk(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/chunk");
+    if args.len() != 2 {
+        let msg = format!("list/chunk expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
     }
 
-    #[test]
-    fn test_native_list_cdr_arity_error() {
-        let result_no_args = eval_list_str("(list/cdr)");
-        assert!(matches!(result_no_args, Err(LispError::ArityMismatch(_))));
+    let size = extract_positive_size(&args[0], "list/chunk")?;
+    let items = extract_list(&args[1], "list/chunk")?;
+    Ok(Expr::List(
+        items
+            .chunks(size)
+            .map(|chunk| Expr::List(chunk.to_vec()))
+            .collect(),
+    ))
+}
 
-        let result_too_many = eval_list_str("(list/cdr '(1) '(2))");
-        assert!(matches!(
-            result_too_many,
-            Err(LispError::ArityMismatch(_))
-        ));
+fn native_list_window(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/window");
+    if args.len() != 2 {
+        let msg = format!("list/window expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let size = extract_positive_size(&args[0], "list/window")?;
+    let items = extract_list(&args[1], "list/window")?;
+    // slice::windows yields nothing when size exceeds the list length.
+    Ok(Expr::List(
+        items
+            .windows(size)
+            .map(|window| Expr::List(window.to_vec()))
+            .collect(),
+    ))
+}
+
+// Helper function for take-while/drop-while: counts the leading elements for which
+// `pred` returns a truthy value.
+fn matching_prefix_len(pred: &Expr, items: &[Expr]) -> Result<usize, LispError> {
+    for (index, item) in items.iter().enumerate() {
+        if !is_truthy(&apply(pred.clone(), vec![item.clone()])?) {
+            return Ok(index);
+        }
+    }
+    Ok(items.len())
+}
+
+fn native_list_take_while(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/take-while");
+    if args.len() != 2 {
+        let msg = format!("list/take-while expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[1], "list/take-while")?;
+    let prefix_len = matching_prefix_len(&args[0], items)?;
+    Ok(Expr::List(items[..prefix_len].to_vec()))
+}
+
+fn native_list_drop_while(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/drop-while");
+    if args.len() != 2 {
+        let msg = format!("list/drop-while expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[1], "list/drop-while")?;
+    let prefix_len = matching_prefix_len(&args[0], items)?;
+    Ok(Expr::List(items[prefix_len..].to_vec()))
+}
+
+fn native_list_enumerate(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/enumerate");
+    if args.len() != 1 {
+        let msg = format!("list/enumerate expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[0], "list/enumerate")?;
+    Ok(Expr::List(
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| Expr::List(vec![Expr::Number(index as f64), item.clone()]))
+            .collect(),
+    ))
+}
+
+// Helper function to read a position argument for insert-at/remove-at/update-at.
+// `limit` is the largest valid index; anything negative, fractional or beyond it is a ValueError.
+fn extract_position(expr: &Expr, limit: usize, op_name: &str) -> Result<usize, LispError> {
+    let index = match expr {
+        Expr::Number(n) => *n,
+        other => {
+            let msg = format!("{} expects a number as index, got {:?}", op_name, other);
+            error!("{}", msg);
+            return Err(LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            });
+        }
+    };
+    if index < 0.0 || index.fract() != 0.0 || index > limit as f64 {
+        let msg = format!(
+            "{} index {} is out of range (expected an integer from 0 to {})",
+            op_name, index, limit
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    Ok(index as usize)
+}
+
+fn native_list_insert_at(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/insert-at");
+    if args.len() != 3 {
+        let msg = format!("list/insert-at expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[2], "list/insert-at")?;
+    // Inserting at index == length appends.
+    let index = extract_position(&args[0], items.len(), "list/insert-at")?;
+    let mut result = items.to_vec();
+    result.insert(index, args[1].clone());
+    Ok(Expr::List(result))
+}
+
+fn native_list_remove_at(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/remove-at");
+    if args.len() != 2 {
+        let msg = format!("list/remove-at expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[1], "list/remove-at")?;
+    if items.is_empty() {
+        let msg = "list/remove-at cannot remove from an empty list".to_string();
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    let index = extract_position(&args[0], items.len() - 1, "list/remove-at")?;
+    let mut result = items.to_vec();
+    result.remove(index);
+    Ok(Expr::List(result))
+}
+
+fn native_list_update_at(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/update-at");
+    if args.len() != 3 {
+        let msg = format!("list/update-at expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let func = args[1].clone();
+    let items = extract_list(&args[2], "list/update-at")?;
+    if items.is_empty() {
+        let msg = "list/update-at cannot update an empty list".to_string();
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    let index = extract_position(&args[0], items.len() - 1, "list/update-at")?;
+    let mut result = items.to_vec();
+    result[index] = apply(func, vec![items[index].clone()])?;
+    Ok(Expr::List(result))
+}
+
+fn native_list_find(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/find");
+    if args.len() != 2 {
+        let msg = format!("list/find expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let pred = args[0].clone();
+    let items = extract_list(&args[1], "list/find")?;
+    // Stops at the first match, so the predicate never sees later elements.
+    for item in items {
+        if is_truthy(&apply(pred.clone(), vec![item.clone()])?) {
+            return Ok(item.clone());
+        }
+    }
+    Ok(Expr::Nil)
+}
+
+// Helper: sorts elements that are all numbers or all strings, ascending or descending.
+fn sort_elements(args: &[Expr], descending: bool, op_name: &str) -> Result<Expr, LispError> {
+    if args.len() != 1 {
+        let msg = format!("{} expects 1 argument, got {}", op_name, args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[0], op_name)?;
+    validate_sort_keys(items, op_name)?;
+    let mut sorted = items.to_vec();
+    if descending {
+        sorted.sort_by(|a, b| compare_sort_keys(b, a));
+    } else {
+        sorted.sort_by(compare_sort_keys);
+    }
+    Ok(Expr::List(sorted))
+}
+
+fn native_list_sort(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/sort");
+    sort_elements(&args, false, "list/sort")
+}
+
+fn native_list_sort_desc(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/sort-desc");
+    sort_elements(&args, true, "list/sort-desc")
+}
+
+fn native_list_truthy_count(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/truthy-count");
+    if args.len() != 1 {
+        let msg = format!("list/truthy-count expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[0], "list/truthy-count")?;
+    let count = items.iter().filter(|item| is_truthy(item)).count();
+    Ok(Expr::Number(count as f64))
+}
+
+/// Default cap on the number of elements a builtin will build from a requested size, as in
+/// `list/range`. Without lazy sequences an accidental huge list would otherwise exhaust
+/// memory before failing.
+pub const DEFAULT_MAX_LIST_SIZE: usize = 1_000_000;
+
+thread_local! {
+    // Maximum number of elements a sized list may have on this thread; 0 means unlimited.
+    static MAX_LIST_SIZE: Cell<usize> = const { Cell::new(DEFAULT_MAX_LIST_SIZE) };
+}
+
+/// Sets the maximum list size builtins such as `list/range` may produce on the current thread.
+/// A limit of 0 disables the check entirely.
+pub fn set_max_list_size(limit: usize) {
+    trace!(limit, "Setting maximum list size");
+    MAX_LIST_SIZE.with(|max| max.set(limit));
+}
+
+/// Returns the maximum list size for the current thread (0 means unlimited).
+pub fn max_list_size() -> usize {
+    MAX_LIST_SIZE.with(|max| max.get())
+}
+
+/// Checks a requested element count against the maximum list size before a builtin allocates
+/// that many elements, returning the count as a `usize`. Expects a non-negative integer count.
+/// Even with the cap disabled, counts too large to ever allocate are rejected.
+pub(crate) fn check_list_size(count: f64, op_name: &str) -> Result<usize, LispError> {
+    let limit = max_list_size();
+    let allocatable = isize::MAX as usize / std::mem::size_of::<Expr>();
+    if (limit != 0 && count > limit as f64) || count > allocatable as f64 {
+        let msg = format!(
+            "{} would produce {} elements, more than the maximum list size of {}",
+            op_name,
+            count,
+            if limit == 0 { allocatable } else { limit }
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    Ok(count as usize)
+}
+
+// Helper function to read a numeric argument of list/range.
+fn extract_range_bound(expr: &Expr, op_name: &str) -> Result<f64, LispError> {
+    match expr {
+        Expr::Number(n) => Ok(*n),
+        other => {
+            let msg = format!("{} expects numbers as arguments, got {:?}", op_name, other);
+            error!("{}", msg);
+            Err(LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            })
+        }
+    }
+}
+
+// Helper for list/range and list/range-inclusive: builds the numbers from `start` towards
+// `end` by `step`, including `end` itself only when `inclusive` and a step lands on it exactly.
+fn build_range(args: &[Expr], inclusive: bool, op_name: &str) -> Result<Expr, LispError> {
+    let (start, end, step) = match args {
+        [end] => (0.0, extract_range_bound(end, op_name)?, 1.0),
+        [start, end] => (
+            extract_range_bound(start, op_name)?,
+            extract_range_bound(end, op_name)?,
+            1.0,
+        ),
+        [start, end, step] => (
+            extract_range_bound(start, op_name)?,
+            extract_range_bound(end, op_name)?,
+            extract_range_bound(step, op_name)?,
+        ),
+        _ => {
+            let msg = format!("{} expects 1 to 3 arguments, got {}", op_name, args.len());
+            error!("{}", msg);
+            return Err(LispError::ArityMismatch(msg));
+        }
+    };
+    if step == 0.0 || !step.is_finite() || !start.is_finite() || !end.is_finite() {
+        let msg = format!(
+            "{} expects finite bounds and a nonzero finite step, got start {}, end {}, step {}",
+            op_name, start, end, step
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+
+    // Counting down for a negative step. A half-open range stops before reaching `end`.
+    let steps = (end - start) / step;
+    let count = if inclusive {
+        (steps.floor() + 1.0).max(0.0)
+    } else {
+        steps.ceil().max(0.0)
+    };
+    let count = check_list_size(count, op_name)?;
+    Ok(Expr::List(
+        (0..count)
+            .map(|i| Expr::Number(start + step * i as f64))
+            .collect(),
+    ))
+}
+
+fn native_list_range(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/range");
+    build_range(&args, false, "list/range")
+}
+
+fn native_list_range_inclusive(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/range-inclusive");
+    build_range(&args, true, "list/range-inclusive")
+}
+
+// Helper: appends the elements of `items` to `out`, splicing nested lists up to `depth` levels.
+fn flatten_into(items: &[Expr], depth: Option<usize>, out: &mut Vec<Expr>) {
+    for item in items {
+        match item {
+            Expr::List(nested) if depth != Some(0) => {
+                flatten_into(nested, depth.map(|d| d - 1), out);
+            }
+            other => out.push(other.clone()),
+        }
+    }
+}
+
+fn native_list_flatten(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/flatten");
+    if args.len() != 1 {
+        let msg = format!("list/flatten expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[0], "list/flatten")?;
+    let mut result = Vec::new();
+    flatten_into(items, None, &mut result);
+    Ok(Expr::List(result))
+}
+
+fn native_list_flatten_once(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/flatten-once");
+    if args.len() != 1 {
+        let msg = format!("list/flatten-once expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_list(&args[0], "list/flatten-once")?;
+    let mut result = Vec::new();
+    flatten_into(items, Some(1), &mut result);
+    Ok(Expr::List(result))
+}
+
+// Native function for pairing keys with values as a map: (list/zip-map keys vals)
+// Extra elements of the longer list are ignored; when a key repeats, the later value wins.
+fn native_list_zip_map(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/zip-map");
+    if args.len() != 2 {
+        let msg = format!("list/zip-map expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let keys = extract_list(&args[0], "list/zip-map")?;
+    let values = extract_list(&args[1], "list/zip-map")?;
+
+    let mut entries: Vec<Expr> = Vec::with_capacity(keys.len().min(values.len()));
+    for (key, value) in keys.iter().zip(values.iter()) {
+        let entry = Expr::List(vec![key.clone(), value.clone()]);
+        match entries
+            .iter_mut()
+            .find(|existing| matches!(existing, Expr::List(kv) if &kv[0] == key))
+        {
+            Some(existing) => *existing = entry,
+            None => entries.push(entry),
+        }
+    }
+    Ok(Expr::List(entries))
+}
+
+// Native function for alternating the elements of several lists: (list/interleave a b ...)
+// Stops once the shortest list runs out, so every round takes one element from each list.
+fn native_list_interleave(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native list function: list/interleave");
+    if args.len() < 2 {
+        let msg = format!(
+            "list/interleave expects at least 2 arguments, got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let lists = args
+        .iter()
+        .map(|arg| extract_list(arg, "list/interleave"))
+        .collect::<Result<Vec<_>, _>>()?;
+    let rounds = lists.iter().map(|list| list.len()).min().unwrap_or(0);
+
+    let mut results = Vec::with_capacity(rounds * lists.len());
+    for i in 0..rounds {
+        results.extend(lists.iter().map(|list| list[i].clone()));
+    }
+    Ok(Expr::List(results))
+}
+
+/// Creates the `list` module with its associated functions.
+pub fn create_list_module() -> Expr {
+    trace!("Creating list module");
+    let list_env_rc = Environment::new(); // Modules have their own environment
+
+    // Scope the mutable borrow so it's dropped before list_env_rc is moved
+    {
+        let mut list_env_borrowed = list_env_rc.borrow_mut();
+        let functions_to_define: HashMap<String, Expr> = HashMap::from([
+            (
+                "length".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/length".to_string(),
+                    func: native_list_length,
+                }),
+            ),
+            (
+                "car".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/car".to_string(),
+                    func: native_list_car,
+                }),
+            ),
+            (
+                "cdr".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/cdr".to_string(),
+                    func: native_list_cdr,
+                }),
+            ),
+            (
+                "last".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/last".to_string(),
+                    func: native_list_last,
+                }),
+            ),
+            (
+                "zip-with".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/zip-with".to_string(),
+                    func: native_list_zip_with,
+                }),
+            ),
+            (
+                "flat-map".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/flat-map".to_string(),
+                    func: native_list_flat_map,
+                }),
+            ),
+            (
+                "repeat".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/repeat".to_string(),
+                    func: native_list_repeat,
+                }),
+            ),
+            (
+                "interpose".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/interpose".to_string(),
+                    func: native_list_interpose,
+                }),
+            ),
+            (
+                "frequencies".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/frequencies".to_string(),
+                    func: native_list_frequencies,
+                }),
+            ),
+            (
+                "sort-by".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/sort-by".to_string(),
+                    func: native_list_sort_by,
+                }),
+            ),
+            (
+                "reductions".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/reductions".to_string(),
+                    func: native_list_reductions,
+                }),
+            ),
+            (
+                "nth".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/nth".to_string(),
+                    func: native_list_nth,
+                }),
+            ),
+            (
+                "second-to-last".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/second-to-last".to_string(),
+                    func: native_list_second_to_last,
+                }),
+            ),
+            (
+                "chunk".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/chunk".to_string(),
+                    func: native_list_chunk,
+                }),
+            ),
+            (
+                "window".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/window".to_string(),
+                    func: native_list_window,
+                }),
+            ),
+            (
+                "take-while".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/take-while".to_string(),
+                    func: native_list_take_while,
+                }),
+            ),
+            (
+                "drop-while".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/drop-while".to_string(),
+                    func: native_list_drop_while,
+                }),
+            ),
+            (
+                "enumerate".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/enumerate".to_string(),
+                    func: native_list_enumerate,
+                }),
+            ),
+            (
+                "insert-at".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/insert-at".to_string(),
+                    func: native_list_insert_at,
+                }),
+            ),
+            (
+                "remove-at".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/remove-at".to_string(),
+                    func: native_list_remove_at,
+                }),
+            ),
+            (
+                "update-at".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/update-at".to_string(),
+                    func: native_list_update_at,
+                }),
+            ),
+            (
+                "find".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/find".to_string(),
+                    func: native_list_find,
+                }),
+            ),
+            (
+                "sort".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/sort".to_string(),
+                    func: native_list_sort,
+                }),
+            ),
+            (
+                "sort-desc".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/sort-desc".to_string(),
+                    func: native_list_sort_desc,
+                }),
+            ),
+            (
+                "truthy-count".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/truthy-count".to_string(),
+                    func: native_list_truthy_count,
+                }),
+            ),
+            (
+                "range".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/range".to_string(),
+                    func: native_list_range,
+                }),
+            ),
+            (
+                "flatten".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/flatten".to_string(),
+                    func: native_list_flatten,
+                }),
+            ),
+            (
+                "flatten-once".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/flatten-once".to_string(),
+                    func: native_list_flatten_once,
+                }),
+            ),
+            (
+                "range-inclusive".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/range-inclusive".to_string(),
+                    func: native_list_range_inclusive,
+                }),
+            ),
+            (
+                "zip-map".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/zip-map".to_string(),
+                    func: native_list_zip_map,
+                }),
+            ),
+            (
+                "interleave".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "list/interleave".to_string(),
+                    func: native_list_interleave,
+                }),
+            ),
+        ]);
+
+        for (name, func_expr) in functions_to_define {
+            list_env_borrowed.define(name, func_expr);
+        }
+    }
+
+    Expr::Module(LispModule {
+        path: PathBuf::from("builtin:list"),
+        env: list_env_rc,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::ast::Expr;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::{eval, LispError};
+    use crate::engine::parser::parse_expr;
+    use crate::logging::init_test_logging;
+    // Removed unused imports for RefCell and Rc for the test module
+
+    // Helper to evaluate a Lisp string in an environment that includes the list module.
+    fn eval_list_str(code: &str) -> Result<Expr, LispError> {
+        init_test_logging();
+        let env = Environment::new_with_prelude(); // Includes math, log, string, list
+        let parse_result = parse_expr(code);
+        let (remaining, parsed_expr_option) = match parse_result {
+            Ok((rem, expr_opt)) => (rem, expr_opt),
+            Err(e) => panic!("Test parse error for code '{}': {}", code, e),
+        };
+
+        if !remaining.is_empty() {
+            panic!(
+                "Unexpected remaining input after parsing in test for code '{}': {}",
+                code, remaining
+            );
+        }
+        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
+        eval(&parsed_expr, env)
+    }
+
+    #[test]
+    fn test_native_list_length_empty_list() {
+        let result = eval_list_str("(list/length '())").unwrap();
+        assert_eq!(result, Expr::Number(0.0));
+    }
+
+    #[test]
+    fn test_native_list_length_nil_direct() {
+        // Note: `nil` itself is not directly callable as a list function argument
+        // in this manner without `quote`. We test `list/length` with `nil` argument.
+        // The function `native_list_length` handles `Expr::Nil` directly.
+        // The `env` variable was unused here as native_list_length doesn't need it for this direct call.
+        init_test_logging(); // Ensure logging is initialized for direct native function tests too
+        let result = native_list_length(vec![Expr::Nil]).unwrap();
+        assert_eq!(result, Expr::Number(0.0));
+    }
+
+    #[test]
+    fn test_native_list_length_non_empty_list() {
+        let result = eval_list_str("(list/length '(1 2 3))").unwrap();
+        assert_eq!(result, Expr::Number(3.0));
+
+        let result_nested = eval_list_str("(list/length '(1 (2 3) 4))").unwrap();
+        assert_eq!(result_nested, Expr::Number(3.0));
+    }
+
+    #[test]
+    fn test_native_list_length_arity_error_no_args() {
+        let result = eval_list_str("(list/length)");
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_list_length_arity_error_too_many_args() {
+        let result = eval_list_str("(list/length '(1) '(2))");
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_list_length_type_error_not_a_list() {
+        let result = eval_list_str("(list/length 123)");
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+
+        let result_string = eval_list_str("(list/length \"hello\")");
+        assert!(matches!(result_string, Err(LispError::TypeError { .. })));
+    }
+
+    // Tests for list/car
+    #[test]
+    fn test_native_list_car_simple() {
+        let result = eval_list_str("(list/car '(1 2 3))").unwrap();
+        assert_eq!(result, Expr::Number(1.0));
+    }
+
+    #[test]
+    fn test_native_list_car_single_element_list() {
+        let result = eval_list_str("(list/car '(a))").unwrap();
+        assert_eq!(result, Expr::Symbol("a".to_string()));
+    }
+
+    #[test]
+    fn test_native_list_car_nested_list() {
+        let result = eval_list_str("(list/car '((1 2) 3))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![Expr::Number(1.0), Expr::Number(2.0)])
+        );
+    }
+
+    #[test]
+    fn test_native_list_car_empty_list_error() {
+        let result = eval_list_str("(list/car '())");
+        assert!(matches!(result, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_list_car_nil_error() {
+        // Evaluating (list/car nil) would first try to evaluate nil as a variable.
+        // To test car with Expr::Nil directly:
+        let result = native_list_car(vec![Expr::Nil]);
+        assert!(matches!(result, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_list_car_type_error() {
+        let result = eval_list_str("(list/car 123)");
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_native_list_car_arity_error() {
+        let result_no_args = eval_list_str("(list/car)");
+        assert!(matches!(result_no_args, Err(LispError::ArityMismatch(_))));
+
+        let result_too_many = eval_list_str("(list/car '(1) '(2))");
+        assert!(matches!(
+            result_too_many,
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    // Tests for list/cdr
+    #[test]
+    fn test_native_list_cdr_simple() {
+        let result = eval_list_str("(list/cdr '(1 2 3))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![Expr::Number(2.0), Expr::Number(3.0)])
+        );
+    }
+
+    #[test]
+    fn test_native_list_cdr_single_element_list() {
+        let result = eval_list_str("(list/cdr '(a))").unwrap();
+        assert_eq!(result, Expr::List(vec![])); // cdr of a single element list is an empty list
+    }
+
+    #[test]
+    fn test_native_list_cdr_nested_list() {
+        let result = eval_list_str("(list/cdr '((1 2) (3 4) 5))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::List(vec![Expr::Number(3.0), Expr::Number(4.0)]),
+                Expr::Number(5.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_cdr_empty_list_error() {
+        let result = eval_list_str("(list/cdr '())");
+        assert!(matches!(result, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_list_cdr_nil_error() {
+        let result = native_list_cdr(vec![Expr::Nil]);
+        assert!(matches!(result, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_list_cdr_type_error() {
+        let result = eval_list_str("(list/cdr 123)");
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_native_list_cdr_arity_error() {
+        let result_no_args = eval_list_str("(list/cdr)");
+        assert!(matches!(result_no_args, Err(LispError::ArityMismatch(_))));
+
+        let result_too_many = eval_list_str("(list/cdr '(1) '(2))");
+        assert!(matches!(
+            result_too_many,
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    // Tests for list/last
+    #[test]
+    fn test_native_list_last_simple() {
+        let result = eval_list_str("(list/last '(1 2 3))").unwrap();
+        assert_eq!(result, Expr::Number(3.0));
+    }
+
+    #[test]
+    fn test_native_list_last_single_element_list() {
+        let result = eval_list_str("(list/last '(a))").unwrap();
+        assert_eq!(result, Expr::Symbol("a".to_string()));
+    }
+
+    #[test]
+    fn test_native_list_last_nested_list() {
+        let result = eval_list_str("(list/last '(1 (2 3)))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![Expr::Number(2.0), Expr::Number(3.0)])
+        );
+    }
+
+    #[test]
+    fn test_native_list_last_empty_list_error() {
+        let result = eval_list_str("(list/last '())");
+        assert!(matches!(result, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_list_last_nil_error() {
+        let result = native_list_last(vec![Expr::Nil]);
+        assert!(matches!(result, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_list_last_type_error() {
+        let result = eval_list_str("(list/last 123)");
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_native_list_last_arity_error() {
+        let result_no_args = eval_list_str("(list/last)");
+        assert!(matches!(result_no_args, Err(LispError::ArityMismatch(_))));
+
+        let result_too_many = eval_list_str("(list/last '(1) '(2))");
+        assert!(matches!(
+            result_too_many,
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    // Tests for list/zip-with
+    #[test]
+    fn test_native_list_zip_with_add() {
+        let result = eval_list_str("(list/zip-with + '(1 2 3) '(10 20 30))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::Number(11.0),
+                Expr::Number(22.0),
+                Expr::Number(33.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_zip_with_truncates_to_shorter() {
+        let result = eval_list_str("(list/zip-with * '(1 2 3 4) '(5 6))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![Expr::Number(5.0), Expr::Number(12.0)])
+        );
+
+        let result_left_shorter = eval_list_str("(list/zip-with + '(1) '(5 6 7))").unwrap();
+        assert_eq!(result_left_shorter, Expr::List(vec![Expr::Number(6.0)]));
+    }
+
+    #[test]
+    fn test_native_list_zip_with_lisp_function() {
+        let result = eval_list_str("(list/zip-with (fn (a b) (- a b)) '(10 20) '(1 2))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![Expr::Number(9.0), Expr::Number(18.0)])
+        );
+    }
+
+    #[test]
+    fn test_native_list_zip_with_type_error() {
+        let result = eval_list_str("(list/zip-with + '(1 2) 3)");
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_native_list_zip_with_arity_error() {
+        let result = eval_list_str("(list/zip-with + '(1 2))");
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/flat-map
+    #[test]
+    fn test_native_list_flat_map() {
+        // There is no list constructor, so pick a literal two-element list per input.
+        let result =
+            eval_list_str("(list/flat-map (fn (x) (if (= x 1) '(a b) '(c d))) '(1 2 1))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::Symbol("a".to_string()),
+                Expr::Symbol("b".to_string()),
+                Expr::Symbol("c".to_string()),
+                Expr::Symbol("d".to_string()),
+                Expr::Symbol("a".to_string()),
+                Expr::Symbol("b".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_flat_map_empty_results() {
+        let result = eval_list_str("(list/flat-map (fn (x) '()) '(1 2 3))").unwrap();
+        assert_eq!(result, Expr::List(vec![]));
+
+        let result_empty_input = eval_list_str("(list/flat-map (fn (x) '(x x)) '())").unwrap();
+        assert_eq!(result_empty_input, Expr::List(vec![]));
+    }
+
+    #[test]
+    fn test_native_list_flat_map_non_list_result_error() {
+        let result = eval_list_str("(list/flat-map (fn (x) (* x 2)) '(1 2))");
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_native_list_flat_map_arity_error() {
+        let result = eval_list_str("(list/flat-map (fn (x) '(x)))");
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/repeat
+    #[test]
+    fn test_native_list_repeat() {
+        let result = eval_list_str("(list/repeat 3 \"ab\")").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::String("ab".to_string()),
+                Expr::String("ab".to_string()),
+                Expr::String("ab".to_string())
+            ])
+        );
+
+        let result_list = eval_list_str("(list/repeat 2 '(1))").unwrap();
+        assert_eq!(
+            result_list,
+            Expr::List(vec![
+                Expr::List(vec![Expr::Number(1.0)]),
+                Expr::List(vec![Expr::Number(1.0)])
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_repeat_zero() {
+        let result = eval_list_str("(list/repeat 0 'x)").unwrap();
+        assert_eq!(result, Expr::List(vec![]));
+    }
+
+    #[test]
+    fn test_native_list_repeat_errors() {
+        let result_negative = eval_list_str("(list/repeat -1 'x)");
+        assert!(matches!(result_negative, Err(LispError::ValueError(_))));
+
+        let result_fraction = eval_list_str("(list/repeat 1.5 'x)");
+        assert!(matches!(result_fraction, Err(LispError::ValueError(_))));
+
+        let result_type = eval_list_str("(list/repeat \"3\" 'x)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/repeat 3)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_list_repeat_respects_max_list_size() {
+        for count in ["1e12", "1e18"] {
+            let result = eval_list_str(&format!("(list/repeat {} 1)", count));
+            assert!(matches!(result, Err(LispError::ValueError(_))));
+        }
+    }
+
+    // Tests for list/interpose
+    #[test]
+    fn test_native_list_interpose() {
+        let result = eval_list_str("(list/interpose \", \" '(\"a\" \"b\" \"c\"))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::String("a".to_string()),
+                Expr::String(", ".to_string()),
+                Expr::String("b".to_string()),
+                Expr::String(", ".to_string()),
+                Expr::String("c".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_interpose_single_element() {
+        let result = eval_list_str("(list/interpose 0 '(1))").unwrap();
+        assert_eq!(result, Expr::List(vec![Expr::Number(1.0)]));
+    }
+
+    #[test]
+    fn test_native_list_interpose_empty_list() {
+        let result = eval_list_str("(list/interpose 0 '())").unwrap();
+        assert_eq!(result, Expr::List(vec![]));
+
+        let result_nil = eval_list_str("(list/interpose 0 nil)").unwrap();
+        assert_eq!(result_nil, Expr::Nil);
+    }
+
+    #[test]
+    fn test_native_list_interpose_errors() {
+        let result_type = eval_list_str("(list/interpose 0 5)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/interpose 0)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/frequencies
+    #[test]
+    fn test_native_list_frequencies() {
+        let result = eval_list_str("(list/frequencies '(a b a (1 2) c (1 2) a))").unwrap();
+        let pair = |item: Expr, count: f64| Expr::List(vec![item, Expr::Number(count)]);
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                pair(Expr::Symbol("a".to_string()), 3.0),
+                pair(Expr::Symbol("b".to_string()), 1.0),
+                pair(Expr::List(vec![Expr::Number(1.0), Expr::Number(2.0)]), 2.0),
+                pair(Expr::Symbol("c".to_string()), 1.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_frequencies_distinguishes_types() {
+        // The number 1 and the string "1" are different elements.
+        let result = eval_list_str("(list/frequencies '(1 \"1\" 1))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::List(vec![Expr::Number(1.0), Expr::Number(2.0)]),
+                Expr::List(vec![Expr::String("1".to_string()), Expr::Number(1.0)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_frequencies_empty_and_errors() {
+        let result_empty = eval_list_str("(list/frequencies '())").unwrap();
+        assert_eq!(result_empty, Expr::List(vec![]));
+
+        let result_type = eval_list_str("(list/frequencies \"abc\")");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/frequencies)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/sort-by
+    #[test]
+    fn test_native_list_sort_by_second_element() {
+        let result = eval_list_str(
+            "(list/sort-by (fn (pair) (list/car (list/cdr pair))) '((a 3) (b 1) (c 2) (d 1)))",
+        )
+        .unwrap();
+        let pair =
+            |name: &str, n: f64| Expr::List(vec![Expr::Symbol(name.to_string()), Expr::Number(n)]);
+        // Stable: (b 1) stays ahead of (d 1)
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                pair("b", 1.0),
+                pair("d", 1.0),
+                pair("c", 2.0),
+                pair("a", 3.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_sort_by_string_keys() {
+        let result =
+            eval_list_str("(list/sort-by identity '(\"pear\" \"apple\" \"fig\"))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::String("apple".to_string()),
+                Expr::String("fig".to_string()),
+                Expr::String("pear".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_sort_by_empty_list() {
+        let result = eval_list_str("(list/sort-by identity '())").unwrap();
+        assert_eq!(result, Expr::List(vec![]));
+    }
+
+    #[test]
+    fn test_native_list_sort_by_errors() {
+        let result_mixed = eval_list_str("(list/sort-by identity '(1 \"a\"))");
+        assert!(matches!(result_mixed, Err(LispError::TypeError { .. })));
+
+        let result_unsortable = eval_list_str("(list/sort-by identity '(a b))");
+        assert!(matches!(
+            result_unsortable,
+            Err(LispError::TypeError { .. })
+        ));
+
+        let result_arity = eval_list_str("(list/sort-by identity)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/reductions
+    #[test]
+    fn test_native_list_reductions_running_sum() {
+        let result = eval_list_str("(list/reductions + 0 '(1 2 3))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::Number(0.0),
+                Expr::Number(1.0),
+                Expr::Number(3.0),
+                Expr::Number(6.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_reductions_lisp_function() {
+        let result = eval_list_str("(list/reductions (fn (acc x) (* acc x)) 1 '(2 3 4))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::Number(1.0),
+                Expr::Number(2.0),
+                Expr::Number(6.0),
+                Expr::Number(24.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_list_reductions_empty_list() {
+        let result = eval_list_str("(list/reductions + 10 '())").unwrap();
+        assert_eq!(result, Expr::List(vec![Expr::Number(10.0)]));
+    }
+
+    #[test]
+    fn test_native_list_reductions_errors() {
+        let result_type = eval_list_str("(list/reductions + 0 5)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_fn_error = eval_list_str("(list/reductions + 0 '(1 \"a\"))");
+        assert!(matches!(result_fn_error, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/reductions + 0)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/nth and list/second-to-last
+    #[test]
+    fn test_native_list_nth_positive_index() {
+        assert_eq!(
+            eval_list_str("(list/nth '(a b c) 0)"),
+            Ok(Expr::Symbol("a".to_string()))
+        );
+        assert_eq!(
+            eval_list_str("(list/nth '(a b c) 2)"),
+            Ok(Expr::Symbol("c".to_string()))
+        );
+        // A whole rational result is an ordinary number, so it works as an index.
+        assert_eq!(
+            eval_list_str("(list/nth '(a b c) (+ 1/2 1/2))"),
+            Ok(Expr::Symbol("b".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_native_list_nth_negative_index() {
+        assert_eq!(
+            eval_list_str("(list/nth '(a b c) -1)"),
+            Ok(Expr::Symbol("c".to_string()))
+        );
+        assert_eq!(
+            eval_list_str("(list/nth '(a b c) -2)"),
+            Ok(Expr::Symbol("b".to_string()))
+        );
+        assert_eq!(
+            eval_list_str("(list/nth '(a b c) -3)"),
+            Ok(Expr::Symbol("a".to_string()))
+        );
     }
 
-    // Tests for list/last
     #[test]
-    fn test_native_list_last_simple() {
-        let result = eval_list_str("(list/last '(1 2 3))").unwrap();
-        assert_eq!(result, Expr::Number(3.0));
+    fn test_native_list_nth_out_of_range() {
+        let result_negative = eval_list_str("(list/nth '(a b c) -4)");
+        assert!(matches!(result_negative, Err(LispError::ValueError(_))));
+
+        let result_positive = eval_list_str("(list/nth '(a b c) 3)");
+        assert!(matches!(result_positive, Err(LispError::ValueError(_))));
+
+        let result_empty = eval_list_str("(list/nth '() -1)");
+        assert!(matches!(result_empty, Err(LispError::ValueError(_))));
     }
 
     #[test]
-    fn test_native_list_last_single_element_list() {
-        let result = eval_list_str("(list/last '(a))").unwrap();
-        assert_eq!(result, Expr::Symbol("a".to_string()));
+    fn test_native_list_nth_errors() {
+        let result_fraction = eval_list_str("(list/nth '(a b) 0.5)");
+        assert!(matches!(result_fraction, Err(LispError::ValueError(_))));
+
+        let result_index_type = eval_list_str("(list/nth '(a b) \"0\")");
+        assert!(matches!(
+            result_index_type,
+            Err(LispError::TypeError { .. })
+        ));
+
+        let result_list_type = eval_list_str("(list/nth 5 0)");
+        assert!(matches!(result_list_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/nth '(a b))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
     }
 
     #[test]
-    fn test_native_list_last_nested_list() {
-        let result = eval_list_str("(list/last '(1 (2 3)))").unwrap();
+    fn test_native_list_second_to_last() {
+        assert_eq!(
+            eval_list_str("(list/second-to-last '(1 2 3))"),
+            Ok(Expr::Number(2.0))
+        );
+        assert_eq!(
+            eval_list_str("(list/second-to-last '(1 2))"),
+            Ok(Expr::Number(1.0))
+        );
+
+        let result_too_short = eval_list_str("(list/second-to-last '(1))");
+        assert!(matches!(result_too_short, Err(LispError::ValueError(_))));
+
+        let result_arity = eval_list_str("(list/second-to-last)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/chunk
+    #[test]
+    fn test_native_list_chunk_evenly_divisible() {
+        let result = eval_list_str("(list/chunk 2 '(1 2 3 4))");
+        let expected = eval_list_str("'((1 2) (3 4))").unwrap();
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_native_list_chunk_unevenly_divisible() {
+        let result = eval_list_str("(list/chunk 2 '(1 2 3 4 5))");
+        let expected = eval_list_str("'((1 2) (3 4) (5))").unwrap();
+        assert_eq!(result, Ok(expected));
+
+        let result_large = eval_list_str("(list/chunk 10 '(1 2))");
+        let expected_large = eval_list_str("'((1 2))").unwrap();
+        assert_eq!(result_large, Ok(expected_large));
+
+        assert_eq!(eval_list_str("(list/chunk 3 '())"), Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_list_chunk_errors() {
+        let result_zero = eval_list_str("(list/chunk 0 '(1 2))");
+        assert!(matches!(result_zero, Err(LispError::ValueError(_))));
+
+        let result_negative = eval_list_str("(list/chunk -1 '(1 2))");
+        assert!(matches!(result_negative, Err(LispError::ValueError(_))));
+
+        let result_fraction = eval_list_str("(list/chunk 1.5 '(1 2))");
+        assert!(matches!(result_fraction, Err(LispError::ValueError(_))));
+
+        let result_type = eval_list_str("(list/chunk \"2\" '(1 2))");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/chunk 2)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/window
+    #[test]
+    fn test_native_list_window() {
+        let result = eval_list_str("(list/window 2 '(1 2 3 4))");
+        let expected = eval_list_str("'((1 2) (2 3) (3 4))").unwrap();
+        assert_eq!(result, Ok(expected));
+
+        let result_full = eval_list_str("(list/window 3 '(1 2 3))");
+        let expected_full = eval_list_str("'((1 2 3))").unwrap();
+        assert_eq!(result_full, Ok(expected_full));
+    }
+
+    #[test]
+    fn test_native_list_window_size_exceeds_length() {
+        assert_eq!(
+            eval_list_str("(list/window 5 '(1 2 3))"),
+            Ok(Expr::List(vec![]))
+        );
+        assert_eq!(eval_list_str("(list/window 1 nil)"), Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_list_window_errors() {
+        let result_zero = eval_list_str("(list/window 0 '(1 2))");
+        assert!(matches!(result_zero, Err(LispError::ValueError(_))));
+
+        let result_list_type = eval_list_str("(list/window 2 5)");
+        assert!(matches!(result_list_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/window 2)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/take-while and list/drop-while
+    #[test]
+    fn test_native_list_take_while_stops_partway() {
+        let result = eval_list_str("(list/take-while (fn (x) (< x 3)) '(1 2 3 1))");
+        let expected = eval_list_str("'(1 2)").unwrap();
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_native_list_drop_while_stops_partway() {
+        let result = eval_list_str("(list/drop-while (fn (x) (< x 3)) '(1 2 3 1))");
+        let expected = eval_list_str("'(3 1)").unwrap();
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_native_list_take_drop_while_all_or_none() {
+        let all = "(fn (x) true)";
+        let none = "(fn (x) false)";
+        let items = eval_list_str("'(1 2 3)").unwrap();
+
+        assert_eq!(
+            eval_list_str(&format!("(list/take-while {} '(1 2 3))", all)),
+            Ok(items.clone())
+        );
+        assert_eq!(
+            eval_list_str(&format!("(list/drop-while {} '(1 2 3))", all)),
+            Ok(Expr::List(vec![]))
+        );
+        assert_eq!(
+            eval_list_str(&format!("(list/take-while {} '(1 2 3))", none)),
+            Ok(Expr::List(vec![]))
+        );
+        assert_eq!(
+            eval_list_str(&format!("(list/drop-while {} '(1 2 3))", none)),
+            Ok(items)
+        );
+    }
+
+    #[test]
+    fn test_native_list_take_drop_while_errors() {
+        let result_type = eval_list_str("(list/take-while (fn (x) true) 5)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_not_fn = eval_list_str("(list/drop-while 5 '(1 2))");
+        assert!(matches!(result_not_fn, Err(LispError::NotAFunction(_))));
+
+        let result_arity = eval_list_str("(list/take-while (fn (x) true))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/enumerate
+    #[test]
+    fn test_native_list_enumerate() {
+        let result = eval_list_str("(list/enumerate '(a b c))");
+        let expected = eval_list_str("'((0 a) (1 b) (2 c))").unwrap();
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_native_list_enumerate_empty() {
+        assert_eq!(
+            eval_list_str("(list/enumerate '())"),
+            Ok(Expr::List(vec![]))
+        );
+        assert_eq!(
+            eval_list_str("(list/enumerate nil)"),
+            Ok(Expr::List(vec![]))
+        );
+    }
+
+    #[test]
+    fn test_native_list_enumerate_errors() {
+        let result_type = eval_list_str("(list/enumerate 5)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/enumerate)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/insert-at and list/remove-at
+    #[test]
+    fn test_native_list_insert_at() {
+        let start = eval_list_str("(list/insert-at 0 'x '(a b))");
+        assert_eq!(start, Ok(eval_list_str("'(x a b)").unwrap()));
+
+        let middle = eval_list_str("(list/insert-at 1 'x '(a b))");
+        assert_eq!(middle, Ok(eval_list_str("'(a x b)").unwrap()));
+
+        let end = eval_list_str("(list/insert-at 2 'x '(a b))");
+        assert_eq!(end, Ok(eval_list_str("'(a b x)").unwrap()));
+
+        let empty = eval_list_str("(list/insert-at 0 'x nil)");
+        assert_eq!(empty, Ok(eval_list_str("'(x)").unwrap()));
+    }
+
+    #[test]
+    fn test_native_list_insert_at_errors() {
+        let result_past_end = eval_list_str("(list/insert-at 3 'x '(a b))");
+        assert!(matches!(result_past_end, Err(LispError::ValueError(_))));
+
+        let result_negative = eval_list_str("(list/insert-at -1 'x '(a b))");
+        assert!(matches!(result_negative, Err(LispError::ValueError(_))));
+
+        let result_type = eval_list_str("(list/insert-at \"0\" 'x '(a b))");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/insert-at 0 'x)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_list_remove_at() {
+        let first = eval_list_str("(list/remove-at 0 '(a b c))");
+        assert_eq!(first, Ok(eval_list_str("'(b c)").unwrap()));
+
+        let last = eval_list_str("(list/remove-at 2 '(a b c))");
+        assert_eq!(last, Ok(eval_list_str("'(a b)").unwrap()));
+    }
+
+    #[test]
+    fn test_native_list_remove_at_errors() {
+        let result_past_end = eval_list_str("(list/remove-at 3 '(a b c))");
+        assert!(matches!(result_past_end, Err(LispError::ValueError(_))));
+
+        let result_fraction = eval_list_str("(list/remove-at 0.5 '(a b c))");
+        assert!(matches!(result_fraction, Err(LispError::ValueError(_))));
+
+        let result_empty = eval_list_str("(list/remove-at 0 '())");
+        assert!(matches!(result_empty, Err(LispError::ValueError(_))));
+
+        let result_arity = eval_list_str("(list/remove-at 0)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/update-at
+    #[test]
+    fn test_native_list_update_at() {
+        let result = eval_list_str("(list/update-at 1 (fn (x) (* x 10)) '(1 2 3))");
         assert_eq!(
             result,
-            Expr::List(vec![Expr::Number(2.0), Expr::Number(3.0)])
+            Ok(Expr::List(vec![
+                Expr::Number(1.0),
+                Expr::Number(20.0),
+                Expr::Number(3.0)
+            ]))
         );
     }
 
     #[test]
-    fn test_native_list_last_empty_list_error() {
-        let result = eval_list_str("(list/last '())");
-        assert!(matches!(result, Err(LispError::ValueError(_))));
+    fn test_native_list_update_at_errors() {
+        let result_range = eval_list_str("(list/update-at 3 (fn (x) x) '(1 2 3))");
+        assert!(matches!(result_range, Err(LispError::ValueError(_))));
+
+        let result_empty = eval_list_str("(list/update-at 0 (fn (x) x) '())");
+        assert!(matches!(result_empty, Err(LispError::ValueError(_))));
+
+        let result_not_fn = eval_list_str("(list/update-at 0 1 '(1 2 3))");
+        assert!(result_not_fn.is_err());
+
+        let result_arity = eval_list_str("(list/update-at 0 (fn (x) x))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
     }
 
+    // Tests for list/find
     #[test]
-    fn test_native_list_last_nil_error() {
-        let result = native_list_last(vec![Expr::Nil]);
-        assert!(matches!(result, Err(LispError::ValueError(_))));
+    fn test_native_list_find() {
+        let found = eval_list_str("(list/find (fn (x) (> x 2)) '(1 2 3 4))");
+        assert_eq!(found, Ok(Expr::Number(3.0)));
+
+        let no_match = eval_list_str("(list/find (fn (x) (> x 10)) '(1 2 3 4))");
+        assert_eq!(no_match, Ok(Expr::Nil));
+
+        let empty = eval_list_str("(list/find (fn (x) true) nil)");
+        assert_eq!(empty, Ok(Expr::Nil));
     }
 
     #[test]
-    fn test_native_list_last_type_error() {
-        let result = eval_list_str("(list/last 123)");
-        assert!(matches!(result, Err(LispError::TypeError { .. })));
+    fn test_native_list_find_short_circuits() {
+        // `>` fails on the string, but the match comes first so it is never compared.
+        let result = eval_list_str("(list/find (fn (x) (> x 1)) '(1 2 \"three\"))");
+        assert_eq!(result, Ok(Expr::Number(2.0)));
     }
 
     #[test]
-    fn test_native_list_last_arity_error() {
-        let result_no_args = eval_list_str("(list/last)");
-        assert!(matches!(result_no_args, Err(LispError::ArityMismatch(_))));
+    fn test_native_list_find_errors() {
+        let result_type = eval_list_str("(list/find (fn (x) true) 42)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
 
-        let result_too_many = eval_list_str("(list/last '(1) '(2))");
+        let result_arity = eval_list_str("(list/find (fn (x) true))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/sort and list/sort-desc
+    #[test]
+    fn test_native_list_sort_numbers() {
+        let ascending = eval_list_str("(list/sort '(3 1 2))");
+        assert_eq!(ascending, Ok(eval_list_str("'(1 2 3)").unwrap()));
+
+        let descending = eval_list_str("(list/sort-desc '(3 1 2))");
+        assert_eq!(descending, Ok(eval_list_str("'(3 2 1)").unwrap()));
+    }
+
+    #[test]
+    fn test_native_list_sort_strings() {
+        let ascending = eval_list_str("(list/sort '(\"pear\" \"apple\" \"fig\"))");
+        assert_eq!(
+            ascending,
+            Ok(eval_list_str("'(\"apple\" \"fig\" \"pear\")").unwrap())
+        );
+
+        let descending = eval_list_str("(list/sort-desc '(\"pear\" \"apple\" \"fig\"))");
+        assert_eq!(
+            descending,
+            Ok(eval_list_str("'(\"pear\" \"fig\" \"apple\")").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_native_list_sort_empty_list() {
+        assert_eq!(eval_list_str("(list/sort nil)"), Ok(Expr::List(vec![])));
+        assert_eq!(
+            eval_list_str("(list/sort-desc '())"),
+            Ok(Expr::List(vec![]))
+        );
+    }
+
+    #[test]
+    fn test_native_list_sort_errors() {
+        let result_mixed = eval_list_str("(list/sort '(1 \"a\"))");
+        assert!(matches!(result_mixed, Err(LispError::TypeError { .. })));
+
+        let result_mixed_desc = eval_list_str("(list/sort-desc '(\"a\" 1))");
         assert!(matches!(
-            result_too_many,
+            result_mixed_desc,
+            Err(LispError::TypeError { .. })
+        ));
+
+        let result_arity = eval_list_str("(list/sort '(1) '(2))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/truthy-count
+    #[test]
+    fn test_native_list_truthy_count() {
+        // Only false and nil are falsy; 0 and "" count as truthy.
+        let result = eval_list_str("(list/truthy-count '(false nil 0 \"\" \"text\" true))");
+        assert_eq!(result, Ok(Expr::Number(4.0)));
+
+        let empty = eval_list_str("(list/truthy-count nil)");
+        assert_eq!(empty, Ok(Expr::Number(0.0)));
+    }
+
+    #[test]
+    fn test_native_list_truthy_count_errors() {
+        let result_type = eval_list_str("(list/truthy-count 42)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/truthy-count)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/range
+    #[test]
+    fn test_native_list_range() {
+        let end_only = eval_list_str("(list/range 4)");
+        assert_eq!(end_only, Ok(eval_list_str("'(0 1 2 3)").unwrap()));
+
+        let start_end = eval_list_str("(list/range 2 5)");
+        assert_eq!(start_end, Ok(eval_list_str("'(2 3 4)").unwrap()));
+
+        let stepped = eval_list_str("(list/range 0 10 3)");
+        assert_eq!(stepped, Ok(eval_list_str("'(0 3 6 9)").unwrap()));
+
+        let descending = eval_list_str("(list/range 3 0 -1)");
+        assert_eq!(descending, Ok(eval_list_str("'(3 2 1)").unwrap()));
+
+        let empty = eval_list_str("(list/range 5 2)");
+        assert_eq!(empty, Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_list_range_respects_max_list_size() {
+        let normal = eval_list_str("(list/range 1000)").unwrap();
+        assert!(matches!(normal, Expr::List(items) if items.len() == 1000));
+
+        let oversized = eval_list_str("(list/range 1e12)");
+        assert!(matches!(oversized, Err(LispError::ValueError(_))));
+
+        set_max_list_size(10);
+        let over_custom_limit = eval_list_str("(list/range 11)");
+        let at_custom_limit = eval_list_str("(list/range 10)");
+        set_max_list_size(DEFAULT_MAX_LIST_SIZE);
+        assert!(matches!(over_custom_limit, Err(LispError::ValueError(_))));
+        assert!(matches!(at_custom_limit, Ok(Expr::List(items)) if items.len() == 10));
+    }
+
+    #[test]
+    fn test_check_list_size() {
+        assert_eq!(check_list_size(0.0, "test"), Ok(0));
+        assert_eq!(check_list_size(1000.0, "test"), Ok(1000));
+        assert!(matches!(
+            check_list_size(1e12, "test"),
+            Err(LispError::ValueError(_))
+        ));
+
+        // Disabling the cap still refuses sizes that could never be allocated.
+        set_max_list_size(0);
+        let unlimited = check_list_size(1e12, "test");
+        let unallocatable = check_list_size(1e18, "test");
+        set_max_list_size(DEFAULT_MAX_LIST_SIZE);
+        assert_eq!(unlimited, Ok(1_000_000_000_000));
+        assert!(matches!(unallocatable, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_list_range_errors() {
+        let result_zero_step = eval_list_str("(list/range 0 10 0)");
+        assert!(matches!(result_zero_step, Err(LispError::ValueError(_))));
+
+        let result_infinite = eval_list_str("(list/range inf)");
+        assert!(matches!(result_infinite, Err(LispError::ValueError(_))));
+
+        let result_type = eval_list_str("(list/range \"10\")");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/range)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/flatten and list/flatten-once
+    #[test]
+    fn test_native_list_flatten_once_vs_deep() {
+        let nested = "'(1 (2 (3 (4))) 5 (6))";
+
+        let once = eval_list_str(&format!("(list/flatten-once {})", nested));
+        assert_eq!(once, Ok(eval_list_str("'(1 2 (3 (4)) 5 6)").unwrap()));
+
+        let deep = eval_list_str(&format!("(list/flatten {})", nested));
+        assert_eq!(deep, Ok(eval_list_str("'(1 2 3 4 5 6)").unwrap()));
+    }
+
+    #[test]
+    fn test_native_list_flatten_keeps_non_list_elements() {
+        let result = eval_list_str("(list/flatten-once '(\"a\" nil (b) ()))");
+        assert_eq!(
+            result,
+            Ok(Expr::List(vec![
+                Expr::String("a".to_string()),
+                Expr::Nil,
+                Expr::Symbol("b".to_string()),
+            ]))
+        );
+
+        assert_eq!(eval_list_str("(list/flatten nil)"), Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_list_flatten_errors() {
+        let result_type = eval_list_str("(list/flatten-once 42)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_list_str("(list/flatten)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/range-inclusive
+    #[test]
+    fn test_native_list_range_inclusive_vs_exclusive() {
+        let inclusive = eval_list_str("(list/range-inclusive 1 5)");
+        assert_eq!(inclusive, Ok(eval_list_str("'(1 2 3 4 5)").unwrap()));
+        let exclusive = eval_list_str("(list/range 1 5)");
+        assert_eq!(exclusive, Ok(eval_list_str("'(1 2 3 4)").unwrap()));
+
+        let end_only = eval_list_str("(list/range-inclusive 3)");
+        assert_eq!(end_only, Ok(eval_list_str("'(0 1 2 3)").unwrap()));
+
+        let descending = eval_list_str("(list/range-inclusive 3 1 -1)");
+        assert_eq!(descending, Ok(eval_list_str("'(3 2 1)").unwrap()));
+
+        let single = eval_list_str("(list/range-inclusive 2 2)");
+        assert_eq!(single, Ok(eval_list_str("'(2)").unwrap()));
+
+        let empty = eval_list_str("(list/range-inclusive 5 2)");
+        assert_eq!(empty, Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_list_range_inclusive_step_overshoot() {
+        // 0, 3, 6, 9: the next step (12) would overshoot 10, so 10 itself is not included.
+        let overshoot = eval_list_str("(list/range-inclusive 0 10 3)");
+        assert_eq!(overshoot, Ok(eval_list_str("'(0 3 6 9)").unwrap()));
+
+        let lands_on_end = eval_list_str("(list/range-inclusive 0 9 3)");
+        assert_eq!(lands_on_end, Ok(eval_list_str("'(0 3 6 9)").unwrap()));
+    }
+
+    #[test]
+    fn test_native_list_range_inclusive_errors() {
+        let result_zero_step = eval_list_str("(list/range-inclusive 0 10 0)");
+        assert!(matches!(result_zero_step, Err(LispError::ValueError(_))));
+
+        let result_oversized = eval_list_str("(list/range-inclusive 1e12)");
+        assert!(matches!(result_oversized, Err(LispError::ValueError(_))));
+
+        let result_arity = eval_list_str("(list/range-inclusive 1 2 3 4)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for list/zip-map
+    #[test]
+    fn test_native_list_zip_map_equal_lengths() {
+        let result = eval_list_str("(list/zip-map '(a b) '(1 2))");
+        assert_eq!(result, eval_list_str("'((a 1) (b 2))"));
+
+        // A repeated key keeps its first position but takes the later value.
+        let result_repeat = eval_list_str("(list/zip-map '(a b a) '(1 2 3))");
+        assert_eq!(result_repeat, eval_list_str("'((a 3) (b 2))"));
+    }
+
+    #[test]
+    fn test_native_list_zip_map_truncates_to_shorter_list() {
+        assert_eq!(
+            eval_list_str("(list/zip-map '(a b c) '(1 2))"),
+            eval_list_str("'((a 1) (b 2))")
+        );
+        assert_eq!(
+            eval_list_str("(list/zip-map '(a) '(1 2 3))"),
+            eval_list_str("'((a 1))")
+        );
+    }
+
+    #[test]
+    fn test_native_list_zip_map_empty_inputs() {
+        assert_eq!(
+            eval_list_str("(list/zip-map '() '(1 2))"),
+            Ok(Expr::List(vec![]))
+        );
+        assert_eq!(
+            eval_list_str("(list/zip-map '(a b) nil)"),
+            Ok(Expr::List(vec![]))
+        );
+        assert!(matches!(
+            eval_list_str("(list/zip-map 1 '(1))"),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_list_str("(list/zip-map '(a))"),
             Err(LispError::ArityMismatch(_))
         ));
     }
+
+    // Tests for list/interleave
+    #[test]
+    fn test_native_list_interleave_equal_lengths() {
+        let result = eval_list_str("(list/interleave '(1 2 3) '(a b c))");
+        assert_eq!(result, eval_list_str("'(1 a 2 b 3 c)"));
+    }
+
+    #[test]
+    fn test_native_list_interleave_stops_at_shortest() {
+        let result = eval_list_str("(list/interleave '(1 2 3) '(a))");
+        assert_eq!(result, eval_list_str("'(1 a)"));
+
+        let result_empty = eval_list_str("(list/interleave '(1 2) nil)");
+        assert_eq!(result_empty, Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_list_interleave_three_lists() {
+        let result = eval_list_str("(list/interleave '(1 2) '(a b) '(x y z))");
+        assert_eq!(result, eval_list_str("'(1 a x 2 b y)"));
+
+        let result_short = eval_list_str("(list/interleave '(1 2 3) '(a b c) '(x))");
+        assert_eq!(result_short, eval_list_str("'(1 a x)"));
+    }
+
+    #[test]
+    fn test_native_list_interleave_errors() {
+        let result_arity = eval_list_str("(list/interleave '(1 2))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+
+        let result_type = eval_list_str("(list/interleave '(1 2) 3)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+    }
 }
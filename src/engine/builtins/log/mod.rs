@@ -1,16 +1,82 @@
 use crate::engine::ast::{Expr, LispModule, NativeFunction};
 use crate::engine::env::Environment;
 use crate::engine::eval::LispError;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::io::Write;
 use std::path::PathBuf;
-use tracing::{instrument, trace};
+use std::rc::Rc;
+use tracing::{error, instrument, trace};
+
+// Where log output goes by default when no writer has been installed.
+#[derive(Debug, Clone, Copy)]
+enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+thread_local! {
+    // Optional override for log output on this thread. When set, both log/info and
+    // log/error write here instead of stdout/stderr.
+    static LOG_WRITER: RefCell<Option<Box<dyn Write>>> = const { RefCell::new(None) };
+}
+
+/// Redirects `log/info` and `log/error` output on the current thread to `writer`.
+/// Passing `None` restores the default stdout/stderr behavior.
+/// Returns the previously installed writer, so callers can restore it afterwards.
+pub fn set_log_writer(writer: Option<Box<dyn Write>>) -> Option<Box<dyn Write>> {
+    LOG_WRITER.with(|cell| cell.replace(writer))
+}
+
+// In-memory writer whose contents stay readable after it is handed to set_log_writer.
+#[derive(Clone, Default)]
+pub(crate) struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SharedBuffer {
+    pub(crate) fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.borrow()).into_owned()
+    }
+}
+
+/// Runs `f` with log output on the current thread redirected into a fresh buffer,
+/// restoring the previous writer afterwards. Returns `f`'s result and the captured text.
+pub(crate) fn capture_log_output<T>(f: impl FnOnce() -> T) -> (T, String) {
+    let buffer = SharedBuffer::default();
+    let previous = set_log_writer(Some(Box::new(buffer.clone())));
+    let result = f();
+    set_log_writer(previous);
+    (result, buffer.contents())
+}
 
 // Helper function for log/info and log/error
 // Prints arguments space-separated.
-fn _log_message_writer(args: Vec<Expr>, writer: fn(&str)) -> Result<Expr, LispError> {
-    let output: Vec<String> = args.iter().map(|arg| arg.to_lisp_string()).collect();
+fn _log_message_writer(args: Vec<Expr>, stream: LogStream) -> Result<Expr, LispError> {
+    let output: Vec<String> = args.iter().map(|arg| arg.to_display_string()).collect();
     let result_string = output.join(" ");
-    writer(&result_string);
+
+    let write_result = LOG_WRITER.with(|cell| match cell.borrow_mut().as_mut() {
+        Some(writer) => writeln!(writer, "{}", result_string),
+        None => match stream {
+            LogStream::Stdout => writeln!(std::io::stdout(), "{}", result_string),
+            LogStream::Stderr => writeln!(std::io::stderr(), "{}", result_string),
+        },
+    });
+    if let Err(e) = write_result {
+        let msg = format!("Failed to write log output: {}", e);
+        error!("{}", msg);
+        return Err(LispError::Evaluation(msg));
+    }
+
     // log functions typically return something like Nil or the printed string.
     // Returning the string allows for potential chaining or inspection in Lisp if desired.
     Ok(Expr::String(result_string))
@@ -19,13 +85,13 @@ fn _log_message_writer(args: Vec<Expr>, writer: fn(&str)) -> Result<Expr, LispEr
 #[instrument(skip(args), ret, err)]
 pub fn native_log_info(args: Vec<Expr>) -> Result<Expr, LispError> {
     trace!("Executing native 'log/info' function");
-    _log_message_writer(args, |s| println!("{}", s))
+    _log_message_writer(args, LogStream::Stdout)
 }
 
 #[instrument(skip(args), ret, err)]
 pub fn native_log_error(args: Vec<Expr>) -> Result<Expr, LispError> {
     trace!("Executing native 'log/error' function");
-    _log_message_writer(args, |s| eprintln!("{}", s))
+    _log_message_writer(args, LogStream::Stderr)
 }
 
 pub fn create_log_module() -> Expr {
@@ -63,7 +129,9 @@ pub fn create_log_module() -> Expr {
 
 #[cfg(test)]
 mod tests {
-    use super::{native_log_error, native_log_info};
+    use super::{
+        SharedBuffer, capture_log_output, native_log_error, native_log_info, set_log_writer,
+    };
     use crate::engine::ast::Expr;
     use crate::logging::init_test_logging;
 
@@ -164,4 +232,37 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_log_output_can_be_captured() {
+        init_test_logging();
+        let buffer = SharedBuffer::default();
+        let previous = set_log_writer(Some(Box::new(buffer.clone())));
+
+        native_log_info(vec![Expr::String("hello".to_string()), Expr::Number(42.0)]).unwrap();
+        native_log_error(vec![Expr::Symbol("oops".to_string())]).unwrap();
+
+        set_log_writer(previous);
+        assert_eq!(buffer.contents(), "hello 42\noops\n");
+
+        // Once restored, further output no longer reaches the buffer.
+        native_log_info(vec![Expr::String("not captured".to_string())]).unwrap();
+        assert_eq!(buffer.contents(), "hello 42\noops\n");
+    }
+
+    #[test]
+    fn test_capture_log_output_restores_previous_writer() {
+        init_test_logging();
+        let outer = SharedBuffer::default();
+        let previous = set_log_writer(Some(Box::new(outer.clone())));
+
+        let (result, captured) =
+            capture_log_output(|| native_log_info(vec![Expr::String("inner".to_string())]));
+        native_log_info(vec![Expr::String("outer".to_string())]).unwrap();
+        set_log_writer(previous);
+
+        assert_eq!(result, Ok(Expr::String("inner".to_string())));
+        assert_eq!(captured, "inner\n");
+        assert_eq!(outer.contents(), "outer\n");
+    }
 }
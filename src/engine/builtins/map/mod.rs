@@ -0,0 +1,848 @@
+//! The `map` module: key/value operations over association lists.
+//!
+//! A map is represented as a list of two-element entries, `((key value) ...)`, and `nil`
+//! is treated as the empty map. Keys are compared with structural equality, and functions
+//! that "modify" a map return a new one, leaving the original untouched.
+
+use crate::engine::ast::{Expr, LispModule, NativeFunction};
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, apply, is_truthy};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::{error, trace};
+
+// Helper function to borrow the entries of a map argument as (key, value) pairs.
+fn extract_entries<'a>(
+    expr: &'a Expr,
+    op_name: &str,
+) -> Result<Vec<(&'a Expr, &'a Expr)>, LispError> {
+    let entries = match expr {
+        Expr::List(list) => list.as_slice(),
+        Expr::Nil => &[],
+        _ => return Err(map_type_error(expr, op_name)),
+    };
+    entries
+        .iter()
+        .map(|entry| match entry {
+            Expr::List(pair) if pair.len() == 2 => Ok((&pair[0], &pair[1])),
+            _ => Err(map_type_error(expr, op_name)),
+        })
+        .collect()
+}
+
+fn map_type_error(found: &Expr, op_name: &str) -> LispError {
+    let type_error = LispError::TypeError {
+        expected: "Map (list of (key value) entries)".to_string(),
+        found: format!("{:?}", found),
+    };
+    error!(operator = %op_name, error = %type_error, "Type error in native map function");
+    type_error
+}
+
+// Builds a new map from `entries` with `key` bound to `value`, replacing the existing
+// entry in place or appending a new one if the key was absent.
+fn with_entry(entries: Vec<(&Expr, &Expr)>, key: &Expr, value: Expr) -> Expr {
+    let mut found = false;
+    let mut new_entries: Vec<Expr> = entries
+        .into_iter()
+        .map(|(k, v)| {
+            if k == key {
+                found = true;
+                Expr::List(vec![k.clone(), value.clone()])
+            } else {
+                Expr::List(vec![k.clone(), v.clone()])
+            }
+        })
+        .collect();
+    if !found {
+        new_entries.push(Expr::List(vec![key.clone(), value]));
+    }
+    Expr::List(new_entries)
+}
+
+// Native function for key lookup with an optional default: (map/get m k [default])
+fn native_map_get(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/get");
+    if args.len() != 2 && args.len() != 3 {
+        let msg = format!("map/get expects 2 or 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let entries = extract_entries(&args[0], "map/get")?;
+    let value = entries
+        .into_iter()
+        .find(|(key, _)| *key == &args[1])
+        .map(|(_, value)| value.clone());
+    Ok(value.unwrap_or_else(|| args.get(2).cloned().unwrap_or(Expr::Nil)))
+}
+
+// Native function for key membership: (map/contains-key? m k)
+fn native_map_contains_key(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/contains-key?");
+    if args.len() != 2 {
+        let msg = format!("map/contains-key? expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let entries = extract_entries(&args[0], "map/contains-key?")?;
+    Ok(Expr::Bool(entries.iter().any(|(key, _)| *key == &args[1])))
+}
+
+// Native function for updating one value with a function: (map/update m k fn)
+fn native_map_update(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/update");
+    if args.len() != 3 {
+        let msg = format!("map/update expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let entries = extract_entries(&args[0], "map/update")?;
+    let key = &args[1];
+    let current = entries
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| (*v).clone())
+        .unwrap_or(Expr::Nil);
+    let updated = apply(args[2].clone(), vec![current])?;
+    Ok(with_entry(entries, key, updated))
+}
+
+// Native function for building a map from alternating keys and values: (map/list->map kvs)
+fn native_map_list_to_map(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/list->map");
+    if args.len() != 1 {
+        let msg = format!("map/list->map expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = match &args[0] {
+        Expr::List(list) => list.as_slice(),
+        Expr::Nil => &[],
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "List".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native map/list->map");
+            return Err(type_error);
+        }
+    };
+    if items.len() % 2 != 0 {
+        let msg = format!(
+            "map/list->map expects an even number of elements (alternating keys and values), got {}",
+            items.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+
+    // Later pairs win when a key repeats, so every key appears once in the result.
+    let mut entries: Vec<Expr> = Vec::with_capacity(items.len() / 2);
+    for pair in items.chunks(2) {
+        let entry = Expr::List(vec![pair[0].clone(), pair[1].clone()]);
+        match entries
+            .iter_mut()
+            .find(|existing| matches!(existing, Expr::List(kv) if kv[0] == pair[0]))
+        {
+            Some(existing) => *existing = entry,
+            None => entries.push(entry),
+        }
+    }
+    Ok(Expr::List(entries))
+}
+
+// Native function for flattening a map into alternating keys and values: (map/map->list m)
+fn native_map_map_to_list(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/map->list");
+    if args.len() != 1 {
+        let msg = format!("map/map->list expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let entries = extract_entries(&args[0], "map/map->list")?;
+    Ok(Expr::List(
+        entries
+            .into_iter()
+            .flat_map(|(key, value)| [key.clone(), value.clone()])
+            .collect(),
+    ))
+}
+
+// Helper function to borrow a list-of-keys argument (a key path for get-in/assoc-in).
+fn extract_keys<'a>(expr: &'a Expr, op_name: &str) -> Result<&'a [Expr], LispError> {
+    match expr {
+        Expr::List(keys) => Ok(keys.as_slice()),
+        Expr::Nil => Ok(&[]),
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "List of keys".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native map function");
+            Err(type_error)
+        }
+    }
+}
+
+// Native function for nested lookup along a key path: (map/get-in m ks)
+fn native_map_get_in(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/get-in");
+    if args.len() != 2 {
+        let msg = format!("map/get-in expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let keys = extract_keys(&args[1], "map/get-in")?;
+    // Only the outermost value must be a map; hitting a non-map part-way down is a miss.
+    extract_entries(&args[0], "map/get-in")?;
+    let mut current = args[0].clone();
+    for key in keys {
+        let Ok(entries) = extract_entries(&current, "map/get-in") else {
+            return Ok(Expr::Nil);
+        };
+        let next = entries
+            .into_iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.clone());
+        match next {
+            Some(value) => current = value,
+            None => return Ok(Expr::Nil),
+        }
+    }
+    Ok(current)
+}
+
+// Recursive helper for assoc-in: sets `value` at `keys` inside `map`, creating empty
+// intermediate maps for missing keys.
+fn assoc_in(map: &Expr, keys: &[Expr], value: Expr) -> Result<Expr, LispError> {
+    let entries = extract_entries(map, "map/assoc-in")?;
+    let (key, rest) = keys
+        .split_first()
+        .expect("assoc_in is only called with a non-empty key path");
+    let new_value = if rest.is_empty() {
+        value
+    } else {
+        let child = entries
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| (*v).clone())
+            .unwrap_or(Expr::Nil);
+        assoc_in(&child, rest, value)?
+    };
+    Ok(with_entry(entries, key, new_value))
+}
+
+// Native function for nested update along a key path: (map/assoc-in m ks v)
+fn native_map_assoc_in(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/assoc-in");
+    if args.len() != 3 {
+        let msg = format!("map/assoc-in expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let keys = extract_keys(&args[1], "map/assoc-in")?;
+    if keys.is_empty() {
+        let msg = "map/assoc-in expects a non-empty key path".to_string();
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    assoc_in(&args[0], keys, args[2].clone())
+}
+
+// Native function for transforming every value: (map/map-values fn m)
+fn native_map_map_values(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/map-values");
+    if args.len() != 2 {
+        let msg = format!("map/map-values expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let entries = extract_entries(&args[1], "map/map-values")?;
+    let new_entries = entries
+        .into_iter()
+        .map(|(key, value)| {
+            let mapped = apply(args[0].clone(), vec![value.clone()])?;
+            Ok(Expr::List(vec![key.clone(), mapped]))
+        })
+        .collect::<Result<Vec<Expr>, LispError>>()?;
+    Ok(Expr::List(new_entries))
+}
+
+// Native function for finding keys by value: (map/keys-where pred m)
+fn native_map_keys_where(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/keys-where");
+    if args.len() != 2 {
+        let msg = format!("map/keys-where expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let entries = extract_entries(&args[1], "map/keys-where")?;
+    let mut keys = Vec::new();
+    for (key, value) in entries {
+        if is_truthy(&apply(args[0].clone(), vec![value.clone()])?) {
+            keys.push(key.clone());
+        }
+    }
+    Ok(Expr::List(keys))
+}
+
+// Native function for keeping only some keys: (map/select-keys m ks)
+// Requested keys missing from `m` are skipped; entries keep their order in `m`.
+fn native_map_select_keys(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/select-keys");
+    if args.len() != 2 {
+        let msg = format!("map/select-keys expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let entries = extract_entries(&args[0], "map/select-keys")?;
+    let keys = extract_keys(&args[1], "map/select-keys")?;
+    let new_entries = entries
+        .into_iter()
+        .filter(|(key, _)| keys.contains(key))
+        .map(|(key, value)| Expr::List(vec![key.clone(), value.clone()]))
+        .collect();
+    Ok(Expr::List(new_entries))
+}
+
+// Native function for removing several keys at once: (map/dissoc-all m ks)
+fn native_map_dissoc_all(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native map function: map/dissoc-all");
+    if args.len() != 2 {
+        let msg = format!("map/dissoc-all expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let entries = extract_entries(&args[0], "map/dissoc-all")?;
+    let keys = extract_keys(&args[1], "map/dissoc-all")?;
+    let new_entries = entries
+        .into_iter()
+        .filter(|(key, _)| !keys.contains(key))
+        .map(|(key, value)| Expr::List(vec![key.clone(), value.clone()]))
+        .collect();
+    Ok(Expr::List(new_entries))
+}
+
+/// Creates the `map` module with its associated functions.
+pub fn create_map_module() -> Expr {
+    trace!("Creating map module");
+    let map_env_rc = Environment::new(); // Modules have their own environment
+
+    // Scope the mutable borrow so it's dropped before map_env_rc is moved
+    {
+        let mut map_env_borrowed = map_env_rc.borrow_mut();
+        let functions_to_define: HashMap<String, Expr> = HashMap::from([
+            (
+                "get".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/get".to_string(),
+                    func: native_map_get,
+                }),
+            ),
+            (
+                "contains-key?".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/contains-key?".to_string(),
+                    func: native_map_contains_key,
+                }),
+            ),
+            (
+                "update".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/update".to_string(),
+                    func: native_map_update,
+                }),
+            ),
+            (
+                "list->map".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/list->map".to_string(),
+                    func: native_map_list_to_map,
+                }),
+            ),
+            (
+                "map->list".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/map->list".to_string(),
+                    func: native_map_map_to_list,
+                }),
+            ),
+            (
+                "get-in".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/get-in".to_string(),
+                    func: native_map_get_in,
+                }),
+            ),
+            (
+                "assoc-in".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/assoc-in".to_string(),
+                    func: native_map_assoc_in,
+                }),
+            ),
+            (
+                "map-values".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/map-values".to_string(),
+                    func: native_map_map_values,
+                }),
+            ),
+            (
+                "keys-where".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/keys-where".to_string(),
+                    func: native_map_keys_where,
+                }),
+            ),
+            (
+                "select-keys".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/select-keys".to_string(),
+                    func: native_map_select_keys,
+                }),
+            ),
+            (
+                "dissoc-all".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "map/dissoc-all".to_string(),
+                    func: native_map_dissoc_all,
+                }),
+            ),
+        ]);
+
+        for (name, func_expr) in functions_to_define {
+            map_env_borrowed.define(name, func_expr);
+        }
+    }
+
+    Expr::Module(LispModule {
+        path: PathBuf::from("builtin:map"),
+        env: map_env_rc,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::eval::eval;
+    use crate::engine::parser::parse_expr;
+    use crate::logging::init_test_logging;
+
+    // Helper to evaluate a Lisp string in an environment that includes the map module.
+    fn eval_map_str(code: &str) -> Result<Expr, LispError> {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let (remaining, parsed_expr_option) = parse_expr(code)
+            .unwrap_or_else(|e| panic!("Test parse error for code '{}': {}", code, e));
+        assert!(
+            remaining.is_empty(),
+            "Unexpected remaining input after parsing in test for code '{}': {}",
+            code,
+            remaining
+        );
+        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
+        eval(&parsed_expr, env)
+    }
+
+    // Tests for map/get
+    #[test]
+    fn test_native_map_get_present_key() {
+        let result = eval_map_str("(map/get '((a 1) (b 2)) 'b)").unwrap();
+        assert_eq!(result, Expr::Number(2.0));
+
+        // Keys use structural equality, so list and string keys work too.
+        let result_list_key = eval_map_str("(map/get '(((1 2) \"pair\")) '(1 2))").unwrap();
+        assert_eq!(result_list_key, Expr::String("pair".to_string()));
+    }
+
+    #[test]
+    fn test_native_map_get_present_key_ignores_default() {
+        let result = eval_map_str("(map/get '((a 1)) 'a 99)").unwrap();
+        assert_eq!(result, Expr::Number(1.0));
+    }
+
+    #[test]
+    fn test_native_map_get_absent_key() {
+        let result = eval_map_str("(map/get '((a 1)) 'z)").unwrap();
+        assert_eq!(result, Expr::Nil);
+
+        let result_default = eval_map_str("(map/get '((a 1)) 'z 99)").unwrap();
+        assert_eq!(result_default, Expr::Number(99.0));
+
+        let result_nil_map = eval_map_str("(map/get nil 'z \"fallback\")").unwrap();
+        assert_eq!(result_nil_map, Expr::String("fallback".to_string()));
+    }
+
+    #[test]
+    fn test_native_map_get_errors() {
+        let result_not_map = eval_map_str("(map/get 42 'a)");
+        assert!(matches!(result_not_map, Err(LispError::TypeError { .. })));
+
+        let result_bad_entry = eval_map_str("(map/get '((a 1) b) 'a)");
+        assert!(matches!(result_bad_entry, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_map_str("(map/get '((a 1)))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for map/contains-key?
+    #[test]
+    fn test_native_map_contains_key() {
+        assert_eq!(
+            eval_map_str("(map/contains-key? '((a 1) (b nil)) 'a)"),
+            Ok(Expr::Bool(true))
+        );
+        // A key bound to nil is still present.
+        assert_eq!(
+            eval_map_str("(map/contains-key? '((a 1) (b nil)) 'b)"),
+            Ok(Expr::Bool(true))
+        );
+        assert_eq!(
+            eval_map_str("(map/contains-key? '((a 1)) 'z)"),
+            Ok(Expr::Bool(false))
+        );
+        assert_eq!(
+            eval_map_str("(map/contains-key? nil 'z)"),
+            Ok(Expr::Bool(false))
+        );
+    }
+
+    #[test]
+    fn test_native_map_contains_key_errors() {
+        let result_not_map = eval_map_str("(map/contains-key? \"abc\" 'a)");
+        assert!(matches!(result_not_map, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_map_str("(map/contains-key? '((a 1)))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for map/update
+    #[test]
+    fn test_native_map_update_existing_key() {
+        let result = eval_map_str("(map/update '((a 1) (b 2)) 'a (fn (v) (+ v 1)))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::List(vec![Expr::Symbol("a".to_string()), Expr::Number(2.0)]),
+                Expr::List(vec![Expr::Symbol("b".to_string()), Expr::Number(2.0)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_map_update_new_key_receives_nil() {
+        let result = eval_map_str("(map/update '((a 1)) 'b (fn (v) (if v v 'fresh)))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::List(vec![Expr::Symbol("a".to_string()), Expr::Number(1.0)]),
+                Expr::List(vec![
+                    Expr::Symbol("b".to_string()),
+                    Expr::Symbol("fresh".to_string())
+                ]),
+            ])
+        );
+
+        let result_nil_map = eval_map_str("(map/update nil 'count (constantly 0))").unwrap();
+        assert_eq!(
+            result_nil_map,
+            Expr::List(vec![Expr::List(vec![
+                Expr::Symbol("count".to_string()),
+                Expr::Number(0.0)
+            ])])
+        );
+    }
+
+    #[test]
+    fn test_native_map_update_errors() {
+        let result_not_map = eval_map_str("(map/update 5 'a identity)");
+        assert!(matches!(result_not_map, Err(LispError::TypeError { .. })));
+
+        // Errors from the update function propagate.
+        let result_fn_error = eval_map_str("(map/update '((a \"x\")) 'a (fn (v) (+ v 1)))");
+        assert!(matches!(result_fn_error, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_map_str("(map/update '((a 1)) 'a)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for map/list->map and map/map->list
+    #[test]
+    fn test_native_map_list_to_map() {
+        let result = eval_map_str("(map/list->map '(a 1 b 2))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::List(vec![Expr::Symbol("a".to_string()), Expr::Number(1.0)]),
+                Expr::List(vec![Expr::Symbol("b".to_string()), Expr::Number(2.0)]),
+            ])
+        );
+
+        // Repeated keys keep the last value, at the key's first position.
+        let result_repeated = eval_map_str("(map/list->map '(a 1 b 2 a 3))").unwrap();
+        assert_eq!(
+            result_repeated,
+            Expr::List(vec![
+                Expr::List(vec![Expr::Symbol("a".to_string()), Expr::Number(3.0)]),
+                Expr::List(vec![Expr::Symbol("b".to_string()), Expr::Number(2.0)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_map_map_to_list() {
+        let result = eval_map_str("(map/map->list '((a 1) (b 2)))").unwrap();
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::Symbol("a".to_string()),
+                Expr::Number(1.0),
+                Expr::Symbol("b".to_string()),
+                Expr::Number(2.0),
+            ])
+        );
+        assert_eq!(eval_map_str("(map/map->list nil)"), Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_map_list_round_trip() {
+        let flat = "'(a 1 \"b\" (2 3) c nil)";
+        let result = eval_map_str(&format!("(map/map->list (map/list->map {}))", flat)).unwrap();
+        let expected = eval_map_str(flat).unwrap();
+        assert_eq!(result, expected);
+
+        let map = "'((x 10) (y 20))";
+        let result_map = eval_map_str(&format!("(map/list->map (map/map->list {}))", map)).unwrap();
+        assert_eq!(result_map, eval_map_str(map).unwrap());
+    }
+
+    #[test]
+    fn test_native_map_list_conversion_errors() {
+        let result_odd = eval_map_str("(map/list->map '(a 1 b))");
+        assert!(matches!(result_odd, Err(LispError::ValueError(_))));
+
+        let result_not_list = eval_map_str("(map/list->map 5)");
+        assert!(matches!(result_not_list, Err(LispError::TypeError { .. })));
+
+        let result_not_map = eval_map_str("(map/map->list '(a 1))");
+        assert!(matches!(result_not_map, Err(LispError::TypeError { .. })));
+    }
+
+    // Tests for map/get-in and map/assoc-in
+    #[test]
+    fn test_native_map_get_in_hits() {
+        let result =
+            eval_map_str("(map/get-in '((db ((host \"localhost\") (port 5432)))) '(db port))");
+        assert_eq!(result, Ok(Expr::Number(5432.0)));
+
+        let result_partial = eval_map_str("(map/get-in '((a ((b 1)))) '(a))");
+        assert_eq!(
+            result_partial,
+            Ok(Expr::List(vec![Expr::List(vec![
+                Expr::Symbol("b".to_string()),
+                Expr::Number(1.0)
+            ])]))
+        );
+
+        let result_empty_path = eval_map_str("(map/get-in '((a 1)) '())");
+        assert_eq!(
+            result_empty_path,
+            Ok(Expr::List(vec![Expr::List(vec![
+                Expr::Symbol("a".to_string()),
+                Expr::Number(1.0)
+            ])]))
+        );
+    }
+
+    #[test]
+    fn test_native_map_get_in_misses() {
+        assert_eq!(
+            eval_map_str("(map/get-in '((a ((b 1)))) '(a c))"),
+            Ok(Expr::Nil)
+        );
+        assert_eq!(
+            eval_map_str("(map/get-in '((a ((b 1)))) '(z b))"),
+            Ok(Expr::Nil)
+        );
+        // Walking past a non-map value is a miss rather than an error.
+        assert_eq!(eval_map_str("(map/get-in '((a 1)) '(a b))"), Ok(Expr::Nil));
+        assert_eq!(eval_map_str("(map/get-in nil '(a))"), Ok(Expr::Nil));
+    }
+
+    #[test]
+    fn test_native_map_assoc_in_existing_path() {
+        let result =
+            eval_map_str("(map/get-in (map/assoc-in '((a ((b 1) (c 2)))) '(a b) 10) '(a b))");
+        assert_eq!(result, Ok(Expr::Number(10.0)));
+
+        let result_sibling =
+            eval_map_str("(map/get-in (map/assoc-in '((a ((b 1) (c 2)))) '(a b) 10) '(a c))");
+        assert_eq!(result_sibling, Ok(Expr::Number(2.0)));
+    }
+
+    #[test]
+    fn test_native_map_assoc_in_creates_intermediate_maps() {
+        let result = eval_map_str("(map/assoc-in nil '(a b c) 1)");
+        let expected = eval_map_str("'((a ((b ((c 1))))))").unwrap();
+        assert_eq!(result, Ok(expected));
+
+        let result_lookup = eval_map_str("(map/get-in (map/assoc-in '((x 0)) '(a b) 1) '(a b))");
+        assert_eq!(result_lookup, Ok(Expr::Number(1.0)));
+    }
+
+    #[test]
+    fn test_native_map_get_in_assoc_in_errors() {
+        let result_not_map = eval_map_str("(map/get-in 42 '(a))");
+        assert!(matches!(result_not_map, Err(LispError::TypeError { .. })));
+
+        let result_bad_path = eval_map_str("(map/get-in '((a 1)) 'a)");
+        assert!(matches!(result_bad_path, Err(LispError::TypeError { .. })));
+
+        let result_empty_path = eval_map_str("(map/assoc-in '((a 1)) '() 2)");
+        assert!(matches!(result_empty_path, Err(LispError::ValueError(_))));
+
+        // Setting beneath a non-map value cannot create an intermediate map.
+        let result_through_value = eval_map_str("(map/assoc-in '((a 1)) '(a b) 2)");
+        assert!(matches!(
+            result_through_value,
+            Err(LispError::TypeError { .. })
+        ));
+
+        let result_arity = eval_map_str("(map/assoc-in '((a 1)) '(a))");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_map_map_values_doubles_values() {
+        let result = eval_map_str("(map/map-values (fn (v) (* v 2)) '((a 1) (b 2) (c 3)))");
+        assert_eq!(result, eval_map_str("'((a 2) (b 4) (c 6))"));
+
+        let result_empty = eval_map_str("(map/map-values (fn (v) (* v 2)) nil)");
+        assert_eq!(result_empty, Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_map_map_values_errors() {
+        let result_not_map = eval_map_str("(map/map-values identity 5)");
+        assert!(matches!(result_not_map, Err(LispError::TypeError { .. })));
+
+        let result_fn_error = eval_map_str("(map/map-values (fn (v) (* v 2)) '((a \"x\")))");
+        assert!(matches!(result_fn_error, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_map_str("(map/map-values identity)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_map_keys_where() {
+        let result = eval_map_str("(map/keys-where (fn (v) (> v 10)) '((a 5) (b 20) (c 15)))");
+        assert_eq!(result, eval_map_str("'(b c)"));
+
+        let result_none = eval_map_str("(map/keys-where (fn (v) (> v 100)) '((a 5) (b 20)))");
+        assert_eq!(result_none, Ok(Expr::List(vec![])));
+
+        let result_empty = eval_map_str("(map/keys-where (fn (v) true) nil)");
+        assert_eq!(result_empty, Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_map_keys_where_errors() {
+        let result_not_map = eval_map_str("(map/keys-where identity '(1 2))");
+        assert!(matches!(result_not_map, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_map_str("(map/keys-where identity)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for map/select-keys
+    #[test]
+    fn test_native_map_select_keys_present_keys() {
+        let result = eval_map_str("(map/select-keys '((a 1) (b 2) (c 3)) '(c a))");
+        assert_eq!(result, eval_map_str("'((a 1) (c 3))"));
+    }
+
+    #[test]
+    fn test_native_map_select_keys_omits_absent_keys() {
+        let result = eval_map_str("(map/select-keys '((a 1) (b 2)) '(b z))");
+        assert_eq!(result, eval_map_str("'((b 2))"));
+
+        let result_none = eval_map_str("(map/select-keys '((a 1)) '(z))");
+        assert_eq!(result_none, Ok(Expr::List(vec![])));
+    }
+
+    #[test]
+    fn test_native_map_select_keys_empty_key_list() {
+        assert_eq!(
+            eval_map_str("(map/select-keys '((a 1) (b 2)) '())"),
+            Ok(Expr::List(vec![]))
+        );
+        assert_eq!(
+            eval_map_str("(map/select-keys nil '(a))"),
+            Ok(Expr::List(vec![]))
+        );
+    }
+
+    #[test]
+    fn test_native_map_select_keys_errors() {
+        assert!(matches!(
+            eval_map_str("(map/select-keys '((a 1)) 'a)"),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_map_str("(map/select-keys '((a 1)))"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    // Tests for map/dissoc-all
+    #[test]
+    fn test_native_map_dissoc_all_present_and_absent_keys() {
+        let result = eval_map_str("(map/dissoc-all '((a 1) (b 2) (c 3)) '(c a))");
+        assert_eq!(result, eval_map_str("'((b 2))"));
+
+        // Keys that are not in the map are ignored.
+        let result_absent = eval_map_str("(map/dissoc-all '((a 1) (b 2)) '(b z))");
+        assert_eq!(result_absent, eval_map_str("'((a 1))"));
+    }
+
+    #[test]
+    fn test_native_map_dissoc_all_empty_key_list() {
+        let map = "'((a 1) (b 2))";
+        assert_eq!(
+            eval_map_str(&format!("(map/dissoc-all {} '())", map)),
+            eval_map_str(map)
+        );
+        assert_eq!(
+            eval_map_str("(map/dissoc-all nil '(a))"),
+            Ok(Expr::List(vec![]))
+        );
+    }
+
+    #[test]
+    fn test_native_map_dissoc_all_errors() {
+        assert!(matches!(
+            eval_map_str("(map/dissoc-all '((a 1)) 'a)"),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_map_str("(map/dissoc-all 5 '(a))"),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_map_str("(map/dissoc-all '((a 1)))"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+}
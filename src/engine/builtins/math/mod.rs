@@ -9,6 +9,8 @@ use tracing::{error, trace};
 fn extract_number(expr: &Expr, op_name: &str) -> Result<f64, LispError> {
     match expr {
         Expr::Number(n) => Ok(*n),
+        // Mixing a rational with anything else promotes it to a float.
+        Expr::Rational { num, den } => Ok(*num as f64 / *den as f64),
         _ => {
             let type_error = LispError::TypeError {
                 expected: "Number".to_string(),
@@ -20,6 +22,36 @@ fn extract_number(expr: &Expr, op_name: &str) -> Result<f64, LispError> {
     }
 }
 
+// Helper: the (numerator, denominator) pairs of `args` when every argument is a rational.
+fn extract_rationals(args: &[Expr]) -> Option<Vec<(i64, i64)>> {
+    args.iter()
+        .map(|arg| match arg {
+            Expr::Rational { num, den } => Some((*num, *den)),
+            _ => None,
+        })
+        .collect()
+}
+
+// Helper: exact sum of rationals, or None if an intermediate value overflows i64.
+fn add_rationals(terms: &[(i64, i64)]) -> Option<Expr> {
+    let (mut num, mut den) = (0i64, 1i64);
+    for &(n, d) in terms {
+        num = num.checked_mul(d)?.checked_add(n.checked_mul(den)?)?;
+        den = den.checked_mul(d)?;
+    }
+    Expr::rational(num, den)
+}
+
+// Helper: exact product of rationals, or None if an intermediate value overflows i64.
+fn multiply_rationals(factors: &[(i64, i64)]) -> Option<Expr> {
+    let (mut num, mut den) = (1i64, 1i64);
+    for &(n, d) in factors {
+        num = num.checked_mul(n)?;
+        den = den.checked_mul(d)?;
+    }
+    Expr::rational(num, den)
+}
+
 #[tracing::instrument(skip(args), ret, err)]
 pub fn native_add(args: Vec<Expr>) -> Result<Expr, LispError> {
     trace!("Executing native '+' function");
@@ -28,6 +60,10 @@ pub fn native_add(args: Vec<Expr>) -> Result<Expr, LispError> {
         // Standard behavior for (+) is 0
         return Ok(Expr::Number(0.0));
     }
+    // All-rational sums stay exact; on overflow they fall back to floating point below.
+    if let Some(sum) = extract_rationals(&args).and_then(|terms| add_rationals(&terms)) {
+        return Ok(sum);
+    }
     for arg in args {
         sum += extract_number(&arg, "+")?;
     }
@@ -50,6 +86,8 @@ pub fn native_equals(args: Vec<Expr>) -> Result<Expr, LispError> {
         return Err(arity_error);
     }
 
+    // Comparison follows IEEE 754: NaN is unequal to everything, including itself, and
+    // -0.0 equals 0.0.
     let first_val = extract_number(&args[0], "=")?;
     for arg_expr in args.iter().skip(1) {
         if first_val != extract_number(arg_expr, "=")? {
@@ -67,6 +105,11 @@ pub fn native_multiply(args: Vec<Expr>) -> Result<Expr, LispError> {
         // Standard behavior for (*) is 1
         return Ok(Expr::Number(1.0));
     }
+    // All-rational products stay exact; on overflow they fall back to floating point below.
+    if let Some(product) = extract_rationals(&args).and_then(|factors| multiply_rationals(&factors))
+    {
+        return Ok(product);
+    }
     for arg in args {
         product *= extract_number(&arg, "*")?;
     }
@@ -166,6 +209,84 @@ define_comparison_fn!(native_greater_than, ">", >);
 define_comparison_fn!(native_less_than_or_equal, "<=", <=);
 define_comparison_fn!(native_greater_than_or_equal, ">=", >=);
 
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_sign(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'sign' function");
+    if args.len() != 1 {
+        let arity_error = LispError::ArityMismatch(format!(
+            "Native 'sign' expects exactly 1 argument, got {}",
+            args.len()
+        ));
+        error!(error = %arity_error, "Arity error in native 'sign'");
+        return Err(arity_error);
+    }
+    let n = extract_number(&args[0], "sign")?;
+    // f64::signum maps 0.0 to 1.0, so zero is handled explicitly. NaN propagates.
+    let sign = if n > 0.0 {
+        1.0
+    } else if n < 0.0 {
+        -1.0
+    } else if n == 0.0 {
+        0.0
+    } else {
+        f64::NAN
+    };
+    Ok(Expr::Number(sign))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_abs_diff(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'abs-diff' function");
+    if args.len() != 2 {
+        let arity_error = LispError::ArityMismatch(format!(
+            "Native 'abs-diff' expects exactly 2 arguments, got {}",
+            args.len()
+        ));
+        error!(error = %arity_error, "Arity error in native 'abs-diff'");
+        return Err(arity_error);
+    }
+    let lhs = extract_number(&args[0], "abs-diff")?;
+    let rhs = extract_number(&args[1], "abs-diff")?;
+    Ok(Expr::Number((lhs - rhs).abs()))
+}
+
+// Helper for 'mod' and 'rem': checks for exactly two numbers and a nonzero divisor.
+fn extract_remainder_operands(args: &[Expr], op_name: &str) -> Result<(f64, f64), LispError> {
+    if args.len() != 2 {
+        let arity_error = LispError::ArityMismatch(format!(
+            "Native '{}' expects exactly 2 arguments, got {}",
+            op_name,
+            args.len()
+        ));
+        error!(error = %arity_error, "Arity error in native '{}'", op_name);
+        return Err(arity_error);
+    }
+    let dividend = extract_number(&args[0], op_name)?;
+    let divisor = extract_number(&args[1], op_name)?;
+    if divisor == 0.0 {
+        let div_zero_error =
+            LispError::DivisionByZero(format!("Division by zero in native '{}'", op_name));
+        error!(error = %div_zero_error, "Division by zero error in native '{}'", op_name);
+        return Err(div_zero_error);
+    }
+    Ok((dividend, divisor))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_modulo(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'mod' function");
+    let (dividend, divisor) = extract_remainder_operands(&args, "mod")?;
+    // The Euclidean remainder is never negative, so (mod -7 2) is 1.
+    Ok(Expr::Number(dividend.rem_euclid(divisor)))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_remainder(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'rem' function");
+    let (dividend, divisor) = extract_remainder_operands(&args, "rem")?;
+    // Truncating remainder: the result takes the sign of the dividend, so (rem -7 2) is -1.
+    Ok(Expr::Number(dividend % divisor))
+}
 
 pub fn create_math_module() -> Expr {
     trace!("Creating math module");
@@ -234,6 +355,34 @@ pub fn create_math_module() -> Expr {
                 func: native_greater_than_or_equal,
             }),
         ),
+        (
+            "sign".to_string(),
+            Expr::NativeFunction(NativeFunction {
+                name: "sign".to_string(),
+                func: native_sign,
+            }),
+        ),
+        (
+            "abs-diff".to_string(),
+            Expr::NativeFunction(NativeFunction {
+                name: "abs-diff".to_string(),
+                func: native_abs_diff,
+            }),
+        ),
+        (
+            "mod".to_string(),
+            Expr::NativeFunction(NativeFunction {
+                name: "mod".to_string(),
+                func: native_modulo,
+            }),
+        ),
+        (
+            "rem".to_string(),
+            Expr::NativeFunction(NativeFunction {
+                name: "rem".to_string(),
+                func: native_remainder,
+            }),
+        ),
     ]);
 
     {
@@ -835,6 +984,145 @@ mod tests {
         };
     }
 
+    // Tests for native_sign and native_abs_diff (module-only, accessed via math/...)
+    fn eval_math_call(op: &str, args: Vec<Expr>) -> Result<Expr, LispError> {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let mut list = vec![Expr::Symbol(format!("math/{}", op))];
+        list.extend(args);
+        eval(&Expr::List(list), env)
+    }
+
+    #[test]
+    fn test_native_sign() {
+        assert_eq!(
+            eval_math_call("sign", vec![Expr::Number(42.5)]),
+            Ok(Expr::Number(1.0))
+        );
+        assert_eq!(
+            eval_math_call("sign", vec![Expr::Number(-3.0)]),
+            Ok(Expr::Number(-1.0))
+        );
+        assert_eq!(
+            eval_math_call("sign", vec![Expr::Number(0.0)]),
+            Ok(Expr::Number(0.0))
+        );
+        assert_eq!(
+            eval_math_call("sign", vec![Expr::Number(-0.0)]),
+            Ok(Expr::Number(0.0))
+        );
+    }
+
+    #[test]
+    fn test_native_sign_errors() {
+        assert!(matches!(
+            eval_math_call("sign", vec![]),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert_eq!(
+            eval_math_call("sign", vec![Expr::Bool(true)]),
+            Err(LispError::TypeError {
+                expected: "Number".to_string(),
+                found: "Bool(true)".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_native_abs_diff() {
+        assert_eq!(
+            eval_math_call("abs-diff", vec![Expr::Number(3.0), Expr::Number(10.0)]),
+            Ok(Expr::Number(7.0))
+        );
+        assert_eq!(
+            eval_math_call("abs-diff", vec![Expr::Number(10.0), Expr::Number(3.0)]),
+            Ok(Expr::Number(7.0))
+        );
+        assert_eq!(
+            eval_math_call("abs-diff", vec![Expr::Number(-2.5), Expr::Number(2.5)]),
+            Ok(Expr::Number(5.0))
+        );
+        assert_eq!(
+            eval_math_call("abs-diff", vec![Expr::Number(4.0), Expr::Number(4.0)]),
+            Ok(Expr::Number(0.0))
+        );
+    }
+
+    #[test]
+    fn test_native_abs_diff_errors() {
+        assert!(matches!(
+            eval_math_call("abs-diff", vec![Expr::Number(1.0)]),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_math_call("abs-diff", vec![Expr::Number(1.0), Expr::Nil]),
+            Err(LispError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_native_modulo() {
+        let cases = [
+            (7.0, 3.0, 1.0),
+            (-7.0, 2.0, 1.0),
+            (7.0, -2.0, 1.0),
+            (5.5, 2.0, 1.5),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(
+                eval_math_call("mod", vec![Expr::Number(a), Expr::Number(b)]),
+                Ok(Expr::Number(expected)),
+                "(math/mod {} {})",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn test_native_remainder() {
+        let cases = [
+            (7.0, 3.0, 1.0),
+            (-7.0, 2.0, -1.0),
+            (7.0, -2.0, 1.0),
+            (5.5, 2.0, 1.5),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(
+                eval_math_call("rem", vec![Expr::Number(a), Expr::Number(b)]),
+                Ok(Expr::Number(expected)),
+                "(math/rem {} {})",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn test_native_modulo_and_remainder_errors() {
+        for op in ["mod", "rem"] {
+            assert!(matches!(
+                eval_math_call(op, vec![Expr::Number(1.0), Expr::Number(0.0)]),
+                Err(LispError::DivisionByZero(_))
+            ));
+            assert!(matches!(
+                eval_math_call(op, vec![Expr::Number(1.0)]),
+                Err(LispError::ArityMismatch(_))
+            ));
+            assert!(matches!(
+                eval_math_call(
+                    op,
+                    vec![Expr::Number(1.0), Expr::Number(2.0), Expr::Number(3.0)]
+                ),
+                Err(LispError::ArityMismatch(_))
+            ));
+            assert!(matches!(
+                eval_math_call(op, vec![Expr::String("4".to_string()), Expr::Number(2.0)]),
+                Err(LispError::TypeError { .. })
+            ));
+        }
+    }
+
     // Tests for native_less_than (<)
     test_comparison_fn!(test_native_less_than_true, "<", native_less_than, 2.0, 5.0, true);
     test_comparison_fn!(test_native_less_than_false_equal, "<", native_less_than, 5.0, 5.0, false);
@@ -866,4 +1154,92 @@ mod tests {
     test_comparison_fn!(test_native_greater_than_or_equal_type_error, ">=", native_greater_than_or_equal, 5.0, Expr::List(vec![Expr::Symbol("quote".to_string()), Expr::Symbol("sym".to_string())]), expected_err_found: "Symbol(\"sym\")");
     test_comparison_fn!(test_native_greater_than_or_equal_arity_too_few, ">=", native_greater_than_or_equal, arity_args: [2.0], expected_len: 1);
     test_comparison_fn!(test_native_greater_than_or_equal_arity_too_many, ">=", native_greater_than_or_equal, arity_args: [2.0, 3.0, 4.0], expected_len: 3);
+
+    #[test]
+    fn test_native_equals_nan_is_unequal_to_everything() {
+        let nan = || Expr::Number(f64::NAN);
+        assert_eq!(
+            eval_math_call("=", vec![nan(), nan()]),
+            Ok(Expr::Bool(false))
+        );
+        assert_eq!(
+            eval_math_call("=", vec![Expr::Number(1.0), nan()]),
+            Ok(Expr::Bool(false))
+        );
+        assert_eq!(
+            eval_math_call("=", vec![nan(), Expr::Number(1.0)]),
+            Ok(Expr::Bool(false))
+        );
+    }
+
+    #[test]
+    fn test_native_equals_signed_zero() {
+        assert_eq!(
+            eval_math_call("=", vec![Expr::Number(-0.0), Expr::Number(0.0)]),
+            Ok(Expr::Bool(true))
+        );
+        assert_eq!(
+            eval_math_call(
+                "=",
+                vec![Expr::Number(0.0), Expr::Number(-0.0), Expr::Number(0.0)]
+            ),
+            Ok(Expr::Bool(true))
+        );
+    }
+
+    // Tests for rational arithmetic
+    fn rational(num: i64, den: i64) -> Expr {
+        Expr::rational(num, den).unwrap()
+    }
+
+    #[test]
+    fn test_add_rationals_stays_exact() {
+        let result = eval_math_call("+", vec![rational(1, 3), rational(1, 6)]);
+        assert_eq!(result, Ok(Expr::Rational { num: 1, den: 2 }));
+
+        // A whole result is an ordinary number.
+        let result_whole = eval_math_call("+", vec![rational(1, 2), rational(1, 2)]);
+        assert_eq!(result_whole, Ok(Expr::Number(1.0)));
+
+        let result_negative = eval_math_call("+", vec![rational(1, 4), rational(-3, 4)]);
+        assert_eq!(result_negative, Ok(Expr::Rational { num: -1, den: 2 }));
+    }
+
+    #[test]
+    fn test_multiply_rationals_stays_exact() {
+        let result = eval_math_call("*", vec![rational(2, 3), rational(3, 4)]);
+        assert_eq!(result, Ok(Expr::Rational { num: 1, den: 2 }));
+    }
+
+    #[test]
+    fn test_rationals_mixed_with_numbers_promote_to_float() {
+        let sum = eval_math_call("+", vec![rational(1, 2), Expr::Number(1.0)]);
+        assert_eq!(sum, Ok(Expr::Number(1.5)));
+
+        let product = eval_math_call("*", vec![Expr::Number(3.0), rational(1, 4)]);
+        assert_eq!(product, Ok(Expr::Number(0.75)));
+
+        // Other operators treat rationals as their float value.
+        let less = eval_math_call("<", vec![rational(1, 3), Expr::Number(0.5)]);
+        assert_eq!(less, Ok(Expr::Bool(true)));
+    }
+
+    #[test]
+    fn test_rational_overflow_falls_back_to_float() {
+        let big = rational(i64::MAX, 2);
+        let result = eval_math_call("*", vec![big.clone(), big]);
+        assert!(matches!(result, Ok(Expr::Number(_))));
+    }
+
+    #[test]
+    fn test_rational_literal_arithmetic() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let (_, expr) = crate::engine::parser::parse_expr("(+ 1/3 1/6)").unwrap();
+        let result = eval(&expr.unwrap(), env);
+        assert_eq!(
+            result.map(|value| value.to_lisp_string()),
+            Ok("1/2".to_string())
+        );
+    }
 }
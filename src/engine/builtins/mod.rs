@@ -1,6 +1,13 @@
+pub mod csv;
+pub mod function;
 pub mod globals;
+pub mod json;
 pub mod log;
+pub mod map;
 pub mod math;
 pub mod special_forms;
 pub mod string;
+pub mod system;
+pub mod value;
+pub mod vector;
 pub mod list;
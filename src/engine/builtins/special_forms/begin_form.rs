@@ -0,0 +1,72 @@
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::{instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_begin(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+    trace!("Executing 'begin' special form");
+    // Expressions run in order in the current environment; the first error aborts the rest.
+    let mut last_value = Expr::Nil;
+    for expr in args {
+        last_value = main_eval(expr, Rc::clone(&env))?;
+    }
+    Ok(last_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::LispError;
+
+    #[test]
+    fn eval_begin_returns_last_value() {
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_str("(begin (log/info \"hi\") (+ 1 2))", env.clone()),
+            Ok(Expr::Number(3.0))
+        );
+        assert_eq!(eval_str("(do 1 2 3)", env), Ok(Expr::Number(3.0)));
+    }
+
+    #[test]
+    fn eval_begin_empty_is_nil() {
+        let env = Environment::new_with_prelude();
+        assert_eq!(eval_str("(begin)", env.clone()), Ok(Expr::Nil));
+        assert_eq!(eval_str("(do)", env), Ok(Expr::Nil));
+    }
+
+    #[test]
+    fn eval_begin_runs_in_current_environment() {
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_str("(begin (let x 2) (let y (* x 10)) (+ x y))", env.clone()),
+            Ok(Expr::Number(22.0))
+        );
+        // Bindings made inside the form remain visible afterwards.
+        assert_eq!(eval_str("y", env.clone()), Ok(Expr::Number(20.0)));
+
+        // A function body can now sequence several expressions.
+        eval_str("(let bump (fn (n) (do (let seen n) (+ n 1))))", env.clone()).unwrap();
+        assert_eq!(eval_str("(bump 4)", env), Ok(Expr::Number(5.0)));
+    }
+
+    #[test]
+    fn eval_begin_failing_middle_expression_aborts() {
+        let env = Environment::new_with_prelude();
+        let result = eval_str(
+            "(begin (let before 1) (undefined-fn) (let after 2))",
+            env.clone(),
+        );
+        assert!(matches!(result, Err(LispError::UndefinedSymbol(_))));
+        assert_eq!(eval_str("before", env.clone()), Ok(Expr::Number(1.0)));
+        assert!(matches!(
+            eval_str("after", env),
+            Err(LispError::UndefinedSymbol(_))
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+use super::threading::thread_first;
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval, is_truthy};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::{debug, error, instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_cond_thread(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+    trace!("Executing 'cond->' special form");
+    let Some((initial, clauses)) = args.split_first() else {
+        error!("'cond->' special form requires at least 1 argument (the initial value), found 0");
+        return Err(LispError::ArityMismatch(
+            "'cond->' expects at least 1 argument, got 0".to_string(),
+        ));
+    };
+
+    let mut value = main_eval(initial, Rc::clone(&env))?;
+    for clause in clauses {
+        let Expr::List(pair) = clause else {
+            return Err(invalid_clause(clause));
+        };
+        let [test, form] = pair.as_slice() else {
+            return Err(invalid_clause(clause));
+        };
+        // Tests see the enclosing environment, not the value being threaded.
+        if is_truthy(&main_eval(test, Rc::clone(&env))?) {
+            debug!(?form, "'cond->' clause test passed, applying form");
+            value = main_eval(&thread_first(form, value, "cond->")?, Rc::clone(&env))?;
+        }
+    }
+    Ok(value)
+}
+
+fn invalid_clause(clause: &Expr) -> LispError {
+    error!(
+        "Each clause in 'cond->' must be a (test form) list, found {:?}",
+        clause
+    );
+    LispError::TypeError {
+        expected: "(test form) clause".to_string(),
+        found: format!("{:?}", clause),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::LispError;
+
+    #[test]
+    fn eval_cond_thread_applies_only_passing_clauses() {
+        let env = Environment::new_with_prelude();
+        eval_str("(let inc (fn (x) (+ x 1)))", env.clone()).unwrap();
+        // (1 + 10) is skipped by the false test, so the result is ((1 * 3) + 1) - 2.
+        let result = eval_str(
+            "(cond-> 1 ((= 1 1) (* 3)) (false (+ 10)) (true inc) (nil (* 100)) (true (- 2)))",
+            env,
+        );
+        assert_eq!(result, Ok(Expr::Number(2.0)));
+    }
+
+    #[test]
+    fn eval_cond_thread_without_clauses_returns_value() {
+        let env = Environment::new_with_prelude();
+        assert_eq!(eval_str("(cond-> (+ 2 3))", env), Ok(Expr::Number(5.0)));
+    }
+
+    #[test]
+    fn eval_cond_thread_does_not_reevaluate_value() {
+        let env = Environment::new_with_prelude();
+        // The threaded list must reach list/car as data, not be evaluated as a call.
+        let result = eval_str("(cond-> '(1 2) (true list/cdr) (true list/car))", env);
+        assert_eq!(result, Ok(Expr::Number(2.0)));
+    }
+
+    #[test]
+    fn eval_cond_thread_errors() {
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_str("(cond->)", env.clone()),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_str("(cond-> 1 (true))", env.clone()),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_str("(cond-> 1 true (+ 1))", env.clone()),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_str("(cond-> 1 (true 5))", env),
+            Err(LispError::TypeError { .. })
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::{debug, error, instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_do_times(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+    trace!("Executing 'do-times' special form");
+    if args.is_empty() {
+        error!("'do-times' special form requires at least 1 argument (repeat count), found 0");
+        return Err(LispError::ArityMismatch(
+            "'do-times' expects at least 1 argument, got 0".to_string(),
+        ));
+    }
+
+    let count = match main_eval(&args[0], Rc::clone(&env))? {
+        Expr::Number(n) if n >= 0.0 && n.fract() == 0.0 => n as usize,
+        Expr::Number(n) => {
+            let msg = format!("'do-times' expects a non-negative integer count, got {}", n);
+            error!("{}", msg);
+            return Err(LispError::ValueError(msg));
+        }
+        other => {
+            error!("'do-times' count must be a number, found {:?}", other);
+            return Err(LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            });
+        }
+    };
+
+    debug!(count, "'do-times' repeating body");
+    for _ in 0..count {
+        for body_expr in &args[1..] {
+            main_eval(body_expr, Rc::clone(&env))?;
+        }
+    }
+    Ok(Expr::Nil)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::LispError;
+
+    #[test]
+    fn eval_do_times_repeats_side_effects() {
+        let env = Environment::new_with_prelude();
+        eval_str("(let counter 0)", env.clone()).unwrap();
+        assert_eq!(
+            eval_str(
+                "(do-times 5 (let counter (+ counter 1)) (let last counter))",
+                env.clone()
+            ),
+            Ok(Expr::Nil)
+        );
+        assert_eq!(eval_str("counter", env.clone()), Ok(Expr::Number(5.0)));
+        assert_eq!(eval_str("last", env), Ok(Expr::Number(5.0)));
+    }
+
+    #[test]
+    fn eval_do_times_zero_skips_body() {
+        let env = Environment::new_with_prelude();
+        eval_str("(let counter 0)", env.clone()).unwrap();
+        assert_eq!(
+            eval_str("(do-times 0 (let counter (+ counter 1)))", env.clone()),
+            Ok(Expr::Nil)
+        );
+        assert_eq!(eval_str("counter", env), Ok(Expr::Number(0.0)));
+    }
+
+    #[test]
+    fn eval_do_times_errors() {
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_str("(do-times -1 1)", env.clone()),
+            Err(LispError::ValueError(_))
+        ));
+        assert!(matches!(
+            eval_str("(do-times 1.5 1)", env.clone()),
+            Err(LispError::ValueError(_))
+        ));
+        assert!(matches!(
+            eval_str(r#"(do-times "3" 1)"#, env.clone()),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_str("(do-times)", env),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+}
@@ -59,6 +59,7 @@ pub fn eval_fn(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, Lis
 
     debug!(parameters = ?param_names, body = ?body_expr, "'fn' creating function");
     let lisp_fn = LispFunction {
+        name: None,
         params: param_names,
         body: Box::new(body_expr),
         closure: Rc::clone(&env),
@@ -95,6 +96,7 @@ mod tests {
                 params,
                 body,
                 closure,
+                ..
             })) => {
                 assert_eq!(params, vec!["x".to_string(), "y".to_string()]);
                 assert_eq!(*body, Expr::Symbol("x".to_string()));
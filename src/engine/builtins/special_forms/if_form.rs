@@ -1,6 +1,6 @@
 use crate::engine::ast::Expr;
 use crate::engine::env::Environment;
-use crate::engine::eval::{LispError, eval as main_eval};
+use crate::engine::eval::{LispError, eval as main_eval, is_truthy};
 use std::cell::RefCell;
 use std::rc::Rc;
 use tracing::{debug, error, instrument, trace};
@@ -26,20 +26,15 @@ pub fn eval_if(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, Lis
     let condition_result = main_eval(condition_expr, Rc::clone(&env))?;
     debug!(?condition_result, "Evaluated 'if' condition");
 
-    match condition_result {
-        Expr::Bool(false) | Expr::Nil => {
-            if let Some(else_expr) = else_expr_opt {
-                trace!("Condition is false-y, evaluating else-branch");
-                main_eval(else_expr, env)
-            } else {
-                trace!("Condition is false-y, no else-branch, returning Nil");
-                Ok(Expr::Nil)
-            }
-        }
-        _ => {
-            trace!("Condition is truthy, evaluating then-branch");
-            main_eval(then_expr, env)
-        }
+    if is_truthy(&condition_result) {
+        trace!("Condition is truthy, evaluating then-branch");
+        main_eval(then_expr, env)
+    } else if let Some(else_expr) = else_expr_opt {
+        trace!("Condition is false-y, evaluating else-branch");
+        main_eval(else_expr, env)
+    } else {
+        trace!("Condition is false-y, no else-branch, returning Nil");
+        Ok(Expr::Nil)
     }
 }
 
@@ -9,6 +9,30 @@ use tracing::{debug, error, instrument, trace};
 #[instrument(skip(args, env), fields(args = ?args), ret, err)]
 pub fn eval_let(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
     trace!("Executing 'let' special form");
+
+    // `(let ((a 1) (b 2)))` defines several bindings in order, each visible to the next.
+    if let [Expr::List(bindings)] = args {
+        let mut last_value = Expr::Nil;
+        for binding in bindings {
+            match binding {
+                Expr::List(pair) if pair.len() == 2 => {
+                    last_value = define_binding(&pair[0], &pair[1], Rc::clone(&env))?;
+                }
+                _ => {
+                    error!(
+                        "Each binding in a multi-binding 'let' must be a (name value) list, found {:?}",
+                        binding
+                    );
+                    return Err(LispError::TypeError {
+                        expected: "(name value) binding".to_string(),
+                        found: format!("{:?}", binding),
+                    });
+                }
+            }
+        }
+        return Ok(last_value);
+    }
+
     if args.len() != 2 {
         error!(
             "'let' special form requires 2 arguments (variable name and value), found {}",
@@ -20,9 +44,15 @@ pub fn eval_let(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, Li
         )));
     }
 
-    let var_name_expr = &args[0];
-    let value_expr = &args[1];
+    define_binding(&args[0], &args[1], env)
+}
 
+// Evaluates `value_expr` and binds it to the symbol `var_name_expr` in `env`.
+fn define_binding(
+    var_name_expr: &Expr,
+    value_expr: &Expr,
+    env: Rc<RefCell<Environment>>,
+) -> Result<Expr, LispError> {
     let var_name = match var_name_expr {
         Expr::Symbol(name) => name.clone(),
         _ => {
@@ -43,7 +73,13 @@ pub fn eval_let(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, Li
     }
 
     debug!(variable_name = %var_name, value_expression = ?value_expr, "'let' binding");
-    let evaluated_value = main_eval(value_expr, Rc::clone(&env))?;
+    let mut evaluated_value = main_eval(value_expr, Rc::clone(&env))?;
+    // An anonymous function takes the name it is first bound to; rebinding keeps that name.
+    if let Expr::Function(lisp_fn) = &mut evaluated_value
+        && lisp_fn.name.is_none()
+    {
+        lisp_fn.name = Some(var_name.clone());
+    }
 
     env.borrow_mut()
         .define(var_name.clone(), evaluated_value.clone());
@@ -54,6 +90,7 @@ pub fn eval_let(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, Li
 #[cfg(test)]
 mod tests {
     use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
     use crate::engine::env::Environment;
     use crate::engine::eval::{LispError, eval};
     use crate::logging::init_test_logging;
@@ -170,4 +207,48 @@ mod tests {
             Err(LispError::ReservedKeyword("quote".to_string()))
         );
     }
+
+    #[test]
+    fn eval_let_multi_binding_defines_all_and_returns_last() {
+        init_test_logging();
+        let env = Environment::new();
+        assert_eq!(
+            eval_str("(let ((a 1) (b 2)))", Rc::clone(&env)),
+            Ok(Expr::Number(2.0))
+        );
+        assert_eq!(env.borrow().get("a"), Some(Expr::Number(1.0)));
+        assert_eq!(env.borrow().get("b"), Some(Expr::Number(2.0)));
+    }
+
+    #[test]
+    fn eval_let_multi_binding_sees_earlier_bindings() {
+        init_test_logging();
+        let env = Environment::new();
+        assert_eq!(
+            eval_str("(let ((a 1) (b a)))", Rc::clone(&env)),
+            Ok(Expr::Number(1.0))
+        );
+        assert_eq!(env.borrow().get("b"), Some(Expr::Number(1.0)));
+    }
+
+    #[test]
+    fn eval_let_multi_binding_empty_returns_nil() {
+        init_test_logging();
+        let env = Environment::new();
+        assert_eq!(eval_str("(let ())", env), Ok(Expr::Nil));
+    }
+
+    #[test]
+    fn eval_let_multi_binding_malformed_binding_error() {
+        init_test_logging();
+        let env = Environment::new();
+        let result = eval_str("(let ((a 1) (b)))", Rc::clone(&env));
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+
+        let result_reserved = eval_str("(let ((if 1)))", env);
+        assert_eq!(
+            result_reserved,
+            Err(LispError::ReservedKeyword("if".to_string()))
+        );
+    }
 }
@@ -1,13 +1,48 @@
 // Declare modules for each special form
+pub mod begin_form;
+pub mod cond_thread_form;
+pub mod do_times_form;
 pub mod fn_form;
 pub mod if_form;
 pub mod let_form;
+pub mod profile_form;
 pub mod quote_form;
+pub mod require_all_form;
 pub mod require_form;
+pub mod some_thread_form;
+pub mod thread_last_form;
+pub mod with_output_to_string_form;
+pub mod with_timeout_form;
+
+// Helpers shared by the threading forms
+mod threading;
+
+// Parses and evaluates one expression; shared by the special form tests.
+#[cfg(test)]
+pub(crate) fn eval_str(
+    code: &str,
+    env: std::rc::Rc<std::cell::RefCell<crate::engine::env::Environment>>,
+) -> Result<crate::engine::ast::Expr, crate::engine::eval::LispError> {
+    crate::logging::init_test_logging();
+    let (_, parsed) = crate::engine::parser::parse_expr(code).expect("test code should parse");
+    crate::engine::eval::eval(
+        &parsed.expect("test code should contain an expression"),
+        env,
+    )
+}
 
 // Re-export public evaluation functions
+pub use begin_form::eval_begin;
+pub use cond_thread_form::eval_cond_thread;
+pub use do_times_form::eval_do_times;
 pub use fn_form::eval_fn;
 pub use if_form::eval_if;
 pub use let_form::eval_let;
+pub use profile_form::eval_profile;
 pub use quote_form::eval_quote;
+pub use require_all_form::eval_require_all;
 pub use require_form::eval_require;
+pub use some_thread_form::eval_some_thread;
+pub use thread_last_form::eval_thread_last;
+pub use with_output_to_string_form::eval_with_output_to_string;
+pub use with_timeout_form::eval_with_timeout;
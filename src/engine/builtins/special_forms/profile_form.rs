@@ -0,0 +1,120 @@
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval, finish_profiling, start_profiling};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use tracing::{instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_profile(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+    trace!("Executing 'profile' special form");
+
+    let previous = start_profiling();
+    let mut body_result = Ok(Expr::Nil);
+    for body_expr in args {
+        body_result = main_eval(body_expr, Rc::clone(&env));
+        if body_result.is_err() {
+            break;
+        }
+    }
+    // Always stop counting, even when the body fails, so later calls are not recorded.
+    let counts = finish_profiling(previous);
+
+    eprint!("{}", format_profile_report(&counts));
+    body_result
+}
+
+/// Renders call counts as one `count name` line per function, most-called first
+/// (ties broken by name).
+pub fn format_profile_report(counts: &HashMap<String, usize>) -> String {
+    let mut rows: Vec<(&String, &usize)> = counts.iter().collect();
+    rows.sort_by(|(name_a, count_a), (name_b, count_b)| {
+        count_b.cmp(count_a).then_with(|| name_a.cmp(name_b))
+    });
+
+    let width = rows.first().map_or(1, |(_, count)| count.to_string().len());
+    let mut report = String::from("profile: calls by function\n");
+    for (name, count) in rows {
+        report.push_str(&format!("{:>width$} {}\n", count, name, width = width));
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_profile_report;
+    use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::{LispError, finish_profiling, start_profiling};
+    use std::collections::HashMap;
+
+    #[test]
+    fn eval_profile_returns_body_value_and_counts_calls() {
+        let env = Environment::new_with_prelude();
+        eval_str("(let double (fn (x) (* x 2)))", env.clone()).unwrap();
+
+        // An outer profile collects everything the inner `profile` form recorded.
+        let previous = start_profiling();
+        let result = eval_str(
+            "(profile (double 1) (double 2) (double 3) (+ (double 4) 1))",
+            env.clone(),
+        );
+        let counts = finish_profiling(previous);
+
+        assert_eq!(result, Ok(Expr::Number(9.0)));
+        assert_eq!(counts.get("double"), Some(&4));
+        assert_eq!(counts.get("*"), Some(&4));
+        assert_eq!(counts.get("+"), Some(&1));
+    }
+
+    #[test]
+    fn eval_profile_counts_functions_passed_as_values() {
+        let env = Environment::new_with_prelude();
+        eval_str("(let neg (fn (x) (- 0 x)))", env.clone()).unwrap();
+        eval_str("(let negate neg)", env.clone()).unwrap();
+
+        let previous = start_profiling();
+        eval_str("(profile (list/sort-by neg '(1 2 3)))", env.clone()).unwrap();
+        eval_str("(profile ((partial negate 1)) ((fn (x) x) 1))", env).unwrap();
+        let counts = finish_profiling(previous);
+
+        // Calls are keyed by the function's own name, however it was reached.
+        assert_eq!(counts.get("neg"), Some(&4));
+        assert_eq!(counts.get("list/sort-by"), Some(&1));
+        // Once to build the closure, once to call it: closures carry their builder's name.
+        assert_eq!(counts.get("partial"), Some(&2));
+        assert_eq!(counts.get("<anonymous>"), Some(&1));
+        assert_eq!(counts.get("negate"), None);
+    }
+
+    #[test]
+    fn eval_profile_stops_counting_after_body() {
+        let env = Environment::new_with_prelude();
+        let previous = start_profiling();
+        let result = eval_str("(profile undefined-thing)", env.clone());
+        eval_str("(+ 1 2)", env).unwrap();
+        let counts = finish_profiling(previous);
+
+        assert!(matches!(result, Err(LispError::UndefinedSymbol(_))));
+        assert_eq!(counts.get("+"), Some(&1));
+    }
+
+    #[test]
+    fn format_profile_report_sorts_by_count_then_name() {
+        let counts = HashMap::from([
+            ("b".to_string(), 3),
+            ("a".to_string(), 3),
+            ("c".to_string(), 12),
+        ]);
+        assert_eq!(
+            format_profile_report(&counts),
+            "profile: calls by function\n12 c\n 3 a\n 3 b\n"
+        );
+        assert_eq!(
+            format_profile_report(&HashMap::new()),
+            "profile: calls by function\n"
+        );
+    }
+}
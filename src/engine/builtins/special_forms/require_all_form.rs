@@ -0,0 +1,79 @@
+use super::require_form::eval_require;
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval};
+use std::cell::RefCell;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+use tracing::{debug, error, instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_require_all(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+    trace!("Executing 'require-all' special form");
+    if args.len() != 1 {
+        let msg = format!(
+            "'require-all' expects 1 argument (directory path), got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let dir_path = match main_eval(&args[0], Rc::clone(&env))? {
+        Expr::String(s) | Expr::Symbol(s) => PathBuf::from(s),
+        other => {
+            error!(
+                "'require-all' argument must evaluate to a string or symbol, found {:?}",
+                other
+            );
+            return Err(LispError::TypeError {
+                expected: "String or Symbol path".to_string(),
+                found: format!("{:?}", other),
+            });
+        }
+    };
+
+    let entries = match fs::read_dir(&dir_path) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            error!(path = %dir_path.display(), "'require-all' directory not found");
+            return Err(LispError::ModuleNotFound(dir_path));
+        }
+        Err(e) => {
+            // Reading a regular file as a directory also ends up here.
+            error!(path = %dir_path.display(), error = %e, "'require-all' could not read directory");
+            return Err(LispError::ModuleIoError {
+                path: dir_path,
+                kind: e.kind(),
+                message: e.to_string(),
+            });
+        }
+    };
+
+    let mut module_files: Vec<(String, PathBuf)> = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| LispError::ModuleIoError {
+                path: dir_path.clone(),
+                kind: e.kind(),
+                message: e.to_string(),
+            })?
+            .path();
+        let is_lisp_file = path.is_file() && path.extension().is_some_and(|ext| ext == "lisp");
+        if let (true, Some(stem)) = (is_lisp_file, path.file_stem()) {
+            module_files.push((stem.to_string_lossy().into_owned(), path));
+        }
+    }
+    // Sort so the result (and load order) does not depend on directory iteration order.
+    module_files.sort();
+
+    let mut modules = Vec::with_capacity(module_files.len());
+    for (stem, path) in module_files {
+        debug!(module = %stem, path = %path.display(), "'require-all' loading module");
+        let path_arg = Expr::String(path.to_string_lossy().into_owned());
+        let module = eval_require(&[path_arg], Rc::clone(&env))?;
+        modules.push(Expr::List(vec![Expr::String(stem), module]));
+    }
+    Ok(Expr::List(modules))
+}
@@ -3,27 +3,70 @@ use crate::engine::ast::{Expr, LispModule};
 use crate::engine::env::Environment;
 use crate::engine::eval::{LispError, eval as main_eval};
 use crate::engine::parser;
+use crate::engine::special_forms as special_form_constants;
 use std::cell::RefCell;
 use std::fs;
 use std::path::PathBuf;
 use std::rc::Rc;
 use tracing::{debug, error, instrument, trace};
 
-#[instrument(skip(args, _env), fields(args = ?args), ret, err)]
-pub fn eval_require(args: &[Expr], _env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+// Marker introducing an alias in `(require path :as alias)`.
+const AS_KEYWORD: &str = ":as";
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_require(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
     trace!("Executing 'require' special form");
-    if args.len() != 1 {
-        let msg = format!(
-            "'require' expects 1 argument (path string or symbol), got {}",
-            args.len()
-        );
-        error!("{}", msg);
-        return Err(LispError::ArityMismatch(msg));
+    // `(require path)` or `(require path :as alias)`; the alias is a literal name, not evaluated.
+    let alias = match args {
+        [_] => None,
+        [
+            _,
+            Expr::Symbol(keyword),
+            Expr::Symbol(name) | Expr::String(name),
+        ] if keyword == AS_KEYWORD => Some(name.clone()),
+        [_, _, _] => {
+            let msg = format!(
+                "'require' expects `{} alias` after the path, got {:?} {:?}",
+                AS_KEYWORD, args[1], args[2]
+            );
+            error!("{}", msg);
+            return Err(LispError::Evaluation(msg));
+        }
+        _ => {
+            let msg = format!(
+                "'require' expects 1 argument (path string or symbol) or 3 with `{} alias`, got {}",
+                AS_KEYWORD,
+                args.len()
+            );
+            error!("{}", msg);
+            return Err(LispError::ArityMismatch(msg));
+        }
+    };
+
+    // Reject a reserved alias before loading, so a failed require leaves nothing in the cache.
+    if let Some(alias) = &alias
+        && special_form_constants::is_special_form(alias)
+    {
+        error!(attempted_keyword = %alias, "Attempted to bind a reserved keyword using 'require :as'");
+        return Err(LispError::ReservedKeyword(alias.clone()));
+    }
+
+    let module = require_module(&args[0], Rc::clone(&env))?;
+    if let Some(alias) = alias {
+        debug!(alias = %alias, "Binding required module to alias");
+        env.borrow_mut().define(alias, module.clone());
     }
+    Ok(module)
+}
 
+// Resolves a single module specifier: built-in modules from the environment first, then
+// files on disk (cached in MODULE_CACHE after the first load).
+fn require_module(
+    unevaluated_arg: &Expr,
+    env: Rc<RefCell<Environment>>,
+) -> Result<Expr, LispError> {
     // The argument to 'require' should be evaluated to get the module name (string or symbol).
-    let unevaluated_arg = &args[0];
-    let evaluated_arg = main_eval(unevaluated_arg, Rc::clone(&_env))?;
+    let evaluated_arg = main_eval(unevaluated_arg, Rc::clone(&env))?;
 
     let module_name_key = match evaluated_arg {
         Expr::String(s) => s.clone(),
@@ -43,7 +86,7 @@ pub fn eval_require(args: &[Expr], _env: Rc<RefCell<Environment>>) -> Result<Exp
 
     // Attempt to load from environment (for built-in modules primarily)
     // This allows `(require 'math)` to find the built-in math module.
-    if let Some(expr) = _env.borrow().get(&module_name_key) {
+    if let Some(expr) = env.borrow().get(&module_name_key) {
         if let Expr::Module(_) = &expr {
             trace!(module_name = %module_name_key, "Found module in environment (likely built-in), returning it.");
             return Ok(expr.clone());
@@ -113,57 +156,47 @@ pub fn eval_require(args: &[Expr], _env: Rc<RefCell<Environment>>) -> Result<Exp
         }
     };
 
-    let module_env = Environment::new_with_prelude();
-    let mut current_module_input: &str = &content;
+    // The module's own bindings live in an environment enclosed by the prelude, so they can be
+    // told apart from prelude names (e.g. by `module-members`) while still seeing the prelude.
+    let module_env = Environment::new_enclosed(Environment::new_with_prelude());
 
-    loop {
-        current_module_input = current_module_input.trim_start();
-        if current_module_input.is_empty() {
-            break;
-        }
-        match parser::parse_expr(current_module_input) {
-            Ok((remaining, ast_option)) => {
-                if let Some(ast) = ast_option {
-                    if let Err(e) = main_eval(&ast, Rc::clone(&module_env)) {
-                        error!(module_path = %canonical_path.display(), error = %e, "Error evaluating expression in module");
-                        return Err(LispError::ModuleLoadError {
-                            path: canonical_path.clone(),
-                            source: Box::new(e),
-                        });
-                    }
-                }
-                // If ast_option is None, it means a comment or whitespace was parsed, so just continue.
-                current_module_input = remaining;
-            }
-            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
-                if !current_module_input.is_empty() {
-                    let parse_err_msg = format!(
-                        "Parsing Error in module '{}': {:?}",
-                        canonical_path.display(),
-                        e
-                    );
-                    error!("{}", parse_err_msg);
-                    return Err(LispError::ModuleLoadError {
-                        path: canonical_path.clone(), // Changed here
-                        source: Box::new(LispError::Evaluation(format!(
-                            "Module parsing error: {}",
-                            parse_err_msg
-                        ))),
-                    });
-                }
-                break;
-            }
-            Err(nom::Err::Incomplete(_)) => {
+    for parsed in parser::Reader::new(&content) {
+        let ast = match parsed {
+            Ok(ast) => ast,
+            Err(parser::ParseError::Incomplete) => {
                 let msg = format!(
                     "Parsing incomplete in module '{}': More input needed.",
                     canonical_path.display()
                 );
                 error!("{}", msg);
                 return Err(LispError::ModuleLoadError {
-                    path: canonical_path.clone(), // Changed here
+                    path: canonical_path.clone(),
                     source: Box::new(LispError::Evaluation(msg)),
                 });
             }
+            Err(e) => {
+                let parse_err_msg = format!(
+                    "Parsing Error in module '{}': {}",
+                    canonical_path.display(),
+                    e
+                );
+                error!("{}", parse_err_msg);
+                return Err(LispError::ModuleLoadError {
+                    path: canonical_path.clone(),
+                    source: Box::new(LispError::Evaluation(format!(
+                        "Module parsing error: {}",
+                        parse_err_msg
+                    ))),
+                });
+            }
+        };
+
+        if let Err(e) = main_eval(&ast, Rc::clone(&module_env)) {
+            error!(module_path = %canonical_path.display(), error = %e, "Error evaluating expression in module");
+            return Err(LispError::ModuleLoadError {
+                path: canonical_path.clone(),
+                source: Box::new(e),
+            });
         }
     }
 
@@ -211,11 +244,8 @@ mod tests {
 
             match parser::parse_expr(current_input) {
                 Ok((remaining, Some(ast))) => {
-                    let eval_result = main_eval(&ast, Rc::clone(&env));
-                    if eval_result.is_err() {
-                        return eval_result; // Propagate errors immediately
-                    }
-                    last_result = Some(eval_result);
+                    let eval_result = main_eval(&ast, Rc::clone(&env))?; // Propagate errors immediately
+                    last_result = Some(Ok(eval_result));
                     current_input = remaining;
                 }
                 Ok((remaining, None)) => {
@@ -504,4 +534,67 @@ mod tests {
             ),
         }
     }
+
+    #[test]
+    fn test_require_with_alias_binds_module() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let result = run_require_expr(
+            "(require \"string\" :as s) (s/concat \"a\" \"b\")",
+            Rc::clone(&env),
+        );
+        assert_eq!(result, Ok(Expr::String("ab".to_string())));
+        assert!(matches!(env.borrow().get("s"), Some(Expr::Module(_))));
+    }
+
+    #[test]
+    fn test_require_file_module_with_alias() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("aliased_module.lisp");
+        fs::write(&file_path, "(let member 7)").unwrap();
+        let canonical_file_path = fs::canonicalize(&file_path).unwrap();
+        MODULE_CACHE.with(|mc| mc.borrow_mut().remove(&canonical_file_path));
+
+        let code = format!("(require \"{}\" :as m) m/member", file_path.display());
+        let result = run_require_expr(&code, Rc::clone(&env));
+        MODULE_CACHE.with(|mc| mc.borrow_mut().remove(&canonical_file_path));
+        assert_eq!(result, Ok(Expr::Number(7.0)));
+    }
+
+    #[test]
+    fn test_require_alias_errors() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            run_require_expr("(require 'math :alias m)", Rc::clone(&env)),
+            Err(LispError::Evaluation(_))
+        ));
+        assert!(matches!(
+            run_require_expr("(require 'math :as)", Rc::clone(&env)),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert_eq!(
+            run_require_expr("(require 'math :as if)", Rc::clone(&env)),
+            Err(LispError::ReservedKeyword("if".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_require_reserved_alias_does_not_load_module() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("rejected_alias_module.lisp");
+        fs::write(&file_path, "(let member 7)").unwrap();
+        let canonical_file_path = fs::canonicalize(&file_path).unwrap();
+        MODULE_CACHE.with(|mc| mc.borrow_mut().remove(&canonical_file_path));
+
+        let code = format!("(require \"{}\" :as if)", file_path.display());
+        let result = run_require_expr(&code, Rc::clone(&env));
+        let cached = MODULE_CACHE.with(|mc| mc.borrow_mut().remove(&canonical_file_path));
+        assert_eq!(result, Err(LispError::ReservedKeyword("if".to_string())));
+        assert!(cached.is_none());
+    }
 }
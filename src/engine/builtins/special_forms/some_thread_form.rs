@@ -0,0 +1,80 @@
+use super::threading::thread_first;
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::{debug, error, instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_some_thread(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+    trace!("Executing 'some->' special form");
+    let Some((initial, forms)) = args.split_first() else {
+        error!("'some->' special form requires at least 1 argument (the initial value), found 0");
+        return Err(LispError::ArityMismatch(
+            "'some->' expects at least 1 argument, got 0".to_string(),
+        ));
+    };
+
+    let mut value = main_eval(initial, Rc::clone(&env))?;
+    for (step, form) in forms.iter().enumerate() {
+        // Once a step yields nil, the remaining forms are never evaluated.
+        if value == Expr::Nil {
+            debug!(step, "'some->' reached nil, skipping remaining forms");
+            return Ok(Expr::Nil);
+        }
+        value = main_eval(&thread_first(form, value, "some->")?, Rc::clone(&env))?;
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::LispError;
+
+    #[test]
+    fn eval_some_thread_completes_chain() {
+        let env = Environment::new_with_prelude();
+        let result = eval_str(
+            "(some-> '((user ((name \"ada\")))) (map/get 'user) (map/get 'name) (string/to-upper))",
+            env,
+        );
+        assert_eq!(result, Ok(Expr::String("ADA".to_string())));
+    }
+
+    #[test]
+    fn eval_some_thread_short_circuits_on_nil() {
+        let env = Environment::new_with_prelude();
+        // string/to-upper would fail on nil, so reaching it would be an error.
+        let result = eval_str(
+            "(some-> '((user ((name \"ada\")))) (map/get 'account) (map/get 'name) string/to-upper)",
+            env.clone(),
+        );
+        assert_eq!(result, Ok(Expr::Nil));
+
+        assert_eq!(eval_str("(some-> nil string/to-upper)", env), Ok(Expr::Nil));
+    }
+
+    #[test]
+    fn eval_some_thread_keeps_false() {
+        let env = Environment::new_with_prelude();
+        // Only nil stops the chain; false is threaded on like any other value.
+        assert_eq!(eval_str("(some-> false not)", env), Ok(Expr::Bool(true)));
+    }
+
+    #[test]
+    fn eval_some_thread_errors() {
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_str("(some->)", env.clone()),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_str("(some-> 1 \"step\")", env),
+            Err(LispError::TypeError { .. })
+        ));
+    }
+}
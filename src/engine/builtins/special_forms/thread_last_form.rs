@@ -0,0 +1,75 @@
+use super::threading::thread_last;
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::{error, instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_thread_last(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+    trace!("Executing '->>' special form");
+    let Some((initial, forms)) = args.split_first() else {
+        error!("'->>' special form requires at least 1 argument (the initial value), found 0");
+        return Err(LispError::ArityMismatch(
+            "'->>' expects at least 1 argument, got 0".to_string(),
+        ));
+    };
+
+    let mut value = main_eval(initial, Rc::clone(&env))?;
+    for form in forms {
+        value = main_eval(&thread_last(form, value, "->>")?, Rc::clone(&env))?;
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::LispError;
+
+    #[test]
+    fn eval_thread_last_through_filter_and_map_style_forms() {
+        let env = Environment::new_with_prelude();
+        let result = eval_str(
+            "(->> '(1 2 3 4 5)
+                  (list/take-while (fn (x) (< x 4)))
+                  (list/flat-map (fn (x) (list/repeat 1 (* x 10)))))",
+            env.clone(),
+        );
+        assert_eq!(result, eval_str("'(10 20 30)", env));
+    }
+
+    #[test]
+    fn eval_thread_last_inserts_value_as_last_argument() {
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_str("(->> 10 (- 3))", env.clone()),
+            Ok(Expr::Number(-7.0))
+        );
+        // A bare function name is called with the value alone.
+        assert_eq!(
+            eval_str(
+                "(->> '(1 2 3) (list/reductions + 0) list/last)",
+                env.clone()
+            ),
+            Ok(Expr::Number(6.0))
+        );
+        assert_eq!(eval_str("(->> 42)", env), Ok(Expr::Number(42.0)));
+    }
+
+    #[test]
+    fn eval_thread_last_errors() {
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_str("(->>)", env.clone()),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_str("(->> 1 2)", env),
+            Err(LispError::TypeError { .. })
+        ));
+    }
+}
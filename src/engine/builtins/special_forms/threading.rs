@@ -0,0 +1,49 @@
+//! Helpers shared by the threading special forms.
+
+use crate::engine::ast::Expr;
+use crate::engine::eval::LispError;
+use crate::engine::special_forms as special_form_constants;
+use tracing::error;
+
+// Rewrites `form` into a call with `value` as its first argument: `(f a b)` becomes
+// `(f value a b)` and a bare `f` becomes `(f value)`.
+pub(crate) fn thread_first(form: &Expr, value: Expr, op_name: &str) -> Result<Expr, LispError> {
+    thread_into(form, value, op_name, false)
+}
+
+// Rewrites `form` into a call with `value` as its last argument: `(f a b)` becomes
+// `(f a b value)` and a bare `f` becomes `(f value)`.
+pub(crate) fn thread_last(form: &Expr, value: Expr, op_name: &str) -> Result<Expr, LispError> {
+    thread_into(form, value, op_name, true)
+}
+
+// The value has already been evaluated, so it is quoted to keep the call from evaluating it
+// a second time.
+fn thread_into(form: &Expr, value: Expr, op_name: &str, last: bool) -> Result<Expr, LispError> {
+    let quoted = Expr::List(vec![
+        Expr::Symbol(special_form_constants::QUOTE.to_string()),
+        value,
+    ]);
+    match form {
+        Expr::List(items) if !items.is_empty() => {
+            let mut call = items.clone();
+            if last {
+                call.push(quoted);
+            } else {
+                call.insert(1, quoted);
+            }
+            Ok(Expr::List(call))
+        }
+        Expr::Symbol(_) => Ok(Expr::List(vec![form.clone(), quoted])),
+        other => {
+            error!(
+                "'{}' steps must be calls or function names, found {:?}",
+                op_name, other
+            );
+            Err(LispError::TypeError {
+                expected: "List or Symbol".to_string(),
+                found: format!("{:?}", other),
+            })
+        }
+    }
+}
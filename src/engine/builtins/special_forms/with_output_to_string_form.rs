@@ -0,0 +1,71 @@
+use crate::engine::ast::Expr;
+use crate::engine::builtins::log::capture_log_output;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::{debug, instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_with_output_to_string(
+    args: &[Expr],
+    env: Rc<RefCell<Environment>>,
+) -> Result<Expr, LispError> {
+    trace!("Executing 'with-output-to-string' special form");
+
+    // Errors in the body propagate as-is; the previous log writer is restored either way.
+    let (body_result, captured) = capture_log_output(|| {
+        for body_expr in args {
+            main_eval(body_expr, Rc::clone(&env))?;
+        }
+        Ok::<(), LispError>(())
+    });
+    body_result?;
+
+    debug!(
+        captured_len = captured.len(),
+        "'with-output-to-string' captured output"
+    );
+    Ok(Expr::String(captured))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::LispError;
+
+    #[test]
+    fn eval_with_output_to_string_captures_multiple_logs() {
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_str(
+                r#"(with-output-to-string (log/info "hello" 1) (log/error "world"))"#,
+                env
+            ),
+            Ok(Expr::String("hello 1\nworld\n".to_string()))
+        );
+    }
+
+    #[test]
+    fn eval_with_output_to_string_empty_body() {
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_str("(with-output-to-string)", env),
+            Ok(Expr::String(String::new()))
+        );
+    }
+
+    #[test]
+    fn eval_with_output_to_string_propagates_errors() {
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_str(
+                r#"(with-output-to-string (log/info "x") undefined-thing)"#,
+                env
+            ),
+            Err(LispError::UndefinedSymbol(_))
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, eval as main_eval, finish_deadline, start_deadline};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::{debug, error, instrument, trace};
+
+#[instrument(skip(args, env), fields(args = ?args), ret, err)]
+pub fn eval_with_timeout(args: &[Expr], env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+    trace!("Executing 'with-timeout' special form");
+    if args.is_empty() {
+        error!("'with-timeout' special form requires at least 1 argument (milliseconds), found 0");
+        return Err(LispError::ArityMismatch(
+            "'with-timeout' expects at least 1 argument, got 0".to_string(),
+        ));
+    }
+
+    let millis = match main_eval(&args[0], Rc::clone(&env))? {
+        Expr::Number(n) if n >= 0.0 && n.fract() == 0.0 => n as u64,
+        Expr::Number(n) => {
+            let msg = format!(
+                "'with-timeout' expects a non-negative integer number of milliseconds, got {}",
+                n
+            );
+            error!("{}", msg);
+            return Err(LispError::ValueError(msg));
+        }
+        other => {
+            error!("'with-timeout' budget must be a number, found {:?}", other);
+            return Err(LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            });
+        }
+    };
+
+    debug!(millis, "'with-timeout' evaluating body under deadline");
+    let previous = start_deadline(millis);
+    let mut body_result = Ok(Expr::Nil);
+    for body_expr in &args[1..] {
+        body_result = main_eval(body_expr, Rc::clone(&env));
+        if body_result.is_err() {
+            break;
+        }
+    }
+    // Always lift the deadline, so code after the form runs unbounded again.
+    finish_deadline(previous);
+    body_result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::ast::Expr;
+    use crate::engine::builtins::special_forms::eval_str;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::LispError;
+
+    #[test]
+    fn eval_with_timeout_returns_fast_body_value() {
+        let env = Environment::new_with_prelude();
+        assert_eq!(
+            eval_str("(with-timeout 10000 (+ 1 2) (* 3 4))", env),
+            Ok(Expr::Number(12.0))
+        );
+    }
+
+    #[test]
+    fn eval_with_timeout_aborts_slow_body() {
+        let env = Environment::new_with_prelude();
+        eval_str("(let counter 0)", env.clone()).unwrap();
+        // Far more iterations than could finish in 20 ms.
+        let result = eval_str(
+            "(with-timeout 20 (do-times 1000000000 (let counter (+ counter 1))))",
+            env.clone(),
+        );
+        assert_eq!(result, Err(LispError::Timeout(20)));
+
+        // The deadline is lifted once the form returns.
+        assert_eq!(eval_str("(+ 1 1)", env), Ok(Expr::Number(2.0)));
+    }
+
+    #[test]
+    fn eval_with_timeout_keeps_sooner_enclosing_deadline() {
+        let env = Environment::new_with_prelude();
+        let result = eval_str(
+            "(with-timeout 20 (with-timeout 100000 (do-times 1000000000 nil)))",
+            env,
+        );
+        assert_eq!(result, Err(LispError::Timeout(20)));
+    }
+
+    #[test]
+    fn eval_with_timeout_invalid_budget() {
+        let env = Environment::new_with_prelude();
+        assert!(matches!(
+            eval_str("(with-timeout -1 nil)", env.clone()),
+            Err(LispError::ValueError(_))
+        ));
+        assert!(matches!(
+            eval_str("(with-timeout \"soon\" nil)", env.clone()),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_str("(with-timeout)", env),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+}
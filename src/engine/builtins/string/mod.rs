@@ -140,6 +140,530 @@ fn string_format(args: Vec<Expr>) -> Result<Expr, LispError> {
     Ok(Expr::String(result_string))
 }
 
+// Native function for parsing an integer with an optional radix: (string/parse-int s [radix])
+fn parse_int(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/parse-int");
+    if args.is_empty() || args.len() > 2 {
+        let msg = format!(
+            "string/parse-int expects 1 or 2 arguments, got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/parse-int")?;
+
+    let radix = match args.get(1) {
+        None => 10,
+        Some(Expr::Number(n)) if n.fract() == 0.0 && (2.0..=36.0).contains(n) => *n as u32,
+        Some(Expr::Number(n)) => {
+            let msg = format!(
+                "string/parse-int radix must be an integer between 2 and 36, got {}",
+                n
+            );
+            error!("{}", msg);
+            return Err(LispError::ValueError(msg));
+        }
+        Some(other) => {
+            let type_error = LispError::TypeError {
+                expected: "Number (for radix)".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native string function");
+            return Err(type_error);
+        }
+    };
+
+    match i64::from_str_radix(&s, radix) {
+        Ok(n) => Ok(Expr::Number(n as f64)),
+        Err(e) => {
+            let msg = format!(
+                "string/parse-int could not parse {:?} in radix {}: {}",
+                s, radix, e
+            );
+            error!("{}", msg);
+            Err(LispError::ValueError(msg))
+        }
+    }
+}
+
+// Native function for rendering an integer in a given radix: (string/format-int n radix)
+fn format_int(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/format-int");
+    if args.len() != 2 {
+        let msg = format!("string/format-int expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let (n, radix) = match (&args[0], &args[1]) {
+        (Expr::Number(n), Expr::Number(radix)) => (*n, *radix),
+        (Expr::Number(_), other) | (other, _) => {
+            let type_error = LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native string function");
+            return Err(type_error);
+        }
+    };
+
+    // i64::MAX as f64 rounds up to 2^63, which is already out of range, while i64::MIN is exact.
+    if n.fract() != 0.0 || !n.is_finite() || n >= i64::MAX as f64 || n < i64::MIN as f64 {
+        let msg = format!("string/format-int expects an integral number, got {}", n);
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    if radix.fract() != 0.0 || !(2.0..=36.0).contains(&radix) {
+        let msg = format!(
+            "string/format-int radix must be an integer between 2 and 36, got {}",
+            radix
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+
+    let radix = radix as u64;
+    let value = n as i64;
+    let mut magnitude = value.unsigned_abs();
+    let mut digits = Vec::new();
+    loop {
+        // from_digit cannot fail: the remainder is always below the (validated) radix.
+        digits.push(std::char::from_digit((magnitude % radix) as u32, radix as u32).unwrap());
+        magnitude /= radix;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    if value < 0 {
+        digits.push('-');
+    }
+    Ok(Expr::String(digits.iter().rev().collect()))
+}
+
+// Native function for splitting on a separator: (string/split s sep [keep-empty])
+// Trailing empty fields are kept by default, like Rust's `str::split`.
+fn split(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/split");
+    if args.len() != 2 && args.len() != 3 {
+        let msg = format!("string/split expects 2 or 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/split")?;
+    let separator = extract_string(&args[1], "string/split")?;
+    if separator.is_empty() {
+        let msg = "string/split separator must not be empty".to_string();
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    let keep_empty = match args.get(2) {
+        None => true,
+        Some(Expr::Bool(b)) => *b,
+        Some(other) => {
+            let type_error = LispError::TypeError {
+                expected: "Bool (for keep-empty)".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native string function");
+            return Err(type_error);
+        }
+    };
+
+    let mut parts: Vec<&str> = s.split(separator.as_str()).collect();
+    if !keep_empty {
+        while parts.last() == Some(&"") {
+            parts.pop();
+        }
+    }
+    Ok(Expr::List(
+        parts
+            .into_iter()
+            .map(|part| Expr::String(part.to_string()))
+            .collect(),
+    ))
+}
+
+// Native function for a character's Unicode scalar value: (string/char-code c)
+// There is no dedicated character type, so a character is a one-character string.
+fn char_code(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/char-code");
+    if args.len() != 1 {
+        let msg = format!("string/char-code expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/char-code")?;
+
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(Expr::Number(c as u32 as f64)),
+        _ => {
+            let msg = format!(
+                "string/char-code expects a single-character string, got {:?}",
+                s
+            );
+            error!("{}", msg);
+            Err(LispError::ValueError(msg))
+        }
+    }
+}
+
+// Native function for the character with a given Unicode scalar value: (string/code-char n)
+fn code_char(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/code-char");
+    if args.len() != 1 {
+        let msg = format!("string/code-char expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let code = match &args[0] {
+        Expr::Number(n) => *n,
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native string function");
+            return Err(type_error);
+        }
+    };
+
+    // Surrogates and values beyond U+10FFFF are not valid chars.
+    let c = (code.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(&code))
+        .then(|| char::from_u32(code as u32))
+        .flatten();
+    match c {
+        Some(c) => Ok(Expr::String(c.to_string())),
+        None => {
+            let msg = format!("string/code-char got an invalid code point: {}", code);
+            error!("{}", msg);
+            Err(LispError::ValueError(msg))
+        }
+    }
+}
+
+// Native function for indexed formatting: (string/format-positional fmt_str arg0 arg1 ...)
+// Each `{N}` is replaced by argument N (0-based), so arguments can be reordered and reused.
+// Braces that do not enclose a plain index are copied through literally.
+fn format_positional(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/format-positional");
+    if args.is_empty() {
+        let msg =
+            "string/format-positional expects at least 1 argument (the format string)".to_string();
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let format_str = extract_string(&args[0], "string/format-positional")?;
+    let format_args = &args[1..];
+
+    let mut result_string = String::new();
+    let mut rest = format_str.as_str();
+    while let Some(open_pos) = rest.find('{') {
+        result_string.push_str(&rest[..open_pos]);
+        let after_open = &rest[open_pos + 1..];
+        let placeholder = after_open
+            .find('}')
+            .map(|close_pos| &after_open[..close_pos])
+            .filter(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()));
+
+        match placeholder {
+            Some(digits) => {
+                let arg = digits
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| format_args.get(index));
+                let Some(arg) = arg else {
+                    let msg = format!(
+                        "string/format-positional placeholder {{{}}} is out of range for {} argument(s)",
+                        digits,
+                        format_args.len()
+                    );
+                    error!("{}", msg);
+                    return Err(LispError::ValueError(msg));
+                };
+                result_string.push_str(&arg.to_lisp_string());
+                rest = &after_open[digits.len() + 1..];
+            }
+            None => {
+                result_string.push('{');
+                rest = after_open;
+            }
+        }
+    }
+    result_string.push_str(rest);
+    Ok(Expr::String(result_string))
+}
+
+// Native function for parsing a floating-point number: (string/parse-float s)
+// Always uses `.` as the decimal separator regardless of locale; surrounding whitespace
+// is not trimmed.
+fn parse_float(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/parse-float");
+    if args.len() != 1 {
+        let msg = format!("string/parse-float expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/parse-float")?;
+
+    match s.parse::<f64>() {
+        Ok(n) => Ok(Expr::Number(n)),
+        Err(e) => {
+            let msg = format!("string/parse-float could not parse {:?}: {}", s, e);
+            error!("{}", msg);
+            Err(LispError::ValueError(msg))
+        }
+    }
+}
+
+// Native function for replacing the first occurrence of a substring: (string/replace-first old new s)
+fn replace_first(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/replace-first");
+    if args.len() != 3 {
+        let msg = format!(
+            "string/replace-first expects 3 arguments, got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let old = extract_string(&args[0], "string/replace-first")?;
+    let new = extract_string(&args[1], "string/replace-first")?;
+    let s = extract_string(&args[2], "string/replace-first")?;
+    Ok(Expr::String(s.replacen(&old, &new, 1)))
+}
+
+// Helper for capitalize/title-case: uppercases the first character of `word` and
+// lowercases the rest. Works per `char`, so multibyte first characters are handled.
+fn capitalize_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+// Native function for capitalizing a string: (string/capitalize s)
+fn capitalize(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/capitalize");
+    if args.len() != 1 {
+        let msg = format!("string/capitalize expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/capitalize")?;
+    Ok(Expr::String(capitalize_word(&s)))
+}
+
+// Native function for capitalizing each whitespace-separated word: (string/title-case s)
+// The original whitespace between words is preserved.
+fn title_case(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/title-case");
+    if args.len() != 1 {
+        let msg = format!("string/title-case expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/title-case")?;
+
+    let mut result_string = String::with_capacity(s.len());
+    let mut word_start = None;
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() {
+            if let Some(start) = word_start.take() {
+                result_string.push_str(&capitalize_word(&s[start..i]));
+            }
+            result_string.push(c);
+        } else if word_start.is_none() {
+            word_start = Some(i);
+        }
+    }
+    if let Some(start) = word_start {
+        result_string.push_str(&capitalize_word(&s[start..]));
+    }
+    Ok(Expr::String(result_string))
+}
+
+// Helper for index-of/last-index-of: converts a byte offset found by `str::find` into
+// a character index, so results line up with other character-based string functions.
+fn char_index_at(s: &str, byte_pos: Option<usize>) -> Expr {
+    match byte_pos {
+        Some(pos) => Expr::Number(s[..pos].chars().count() as f64),
+        None => Expr::Nil,
+    }
+}
+
+// Native function for finding the first occurrence of a substring: (string/index-of s sub)
+fn index_of(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/index-of");
+    if args.len() != 2 {
+        let msg = format!("string/index-of expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/index-of")?;
+    let needle = extract_string(&args[1], "string/index-of")?;
+    Ok(char_index_at(&s, s.find(&needle)))
+}
+
+// Native function for finding the last occurrence of a substring: (string/last-index-of s sub)
+fn last_index_of(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/last-index-of");
+    if args.len() != 2 {
+        let msg = format!(
+            "string/last-index-of expects 2 arguments, got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/last-index-of")?;
+    let needle = extract_string(&args[1], "string/last-index-of")?;
+    Ok(char_index_at(&s, s.rfind(&needle)))
+}
+
+// Helper for case-fold-eq?: approximates Unicode full case folding. Uppercasing first expands
+// characters such as `ß` to `SS`, so "straße" and "STRASSE" fold to the same string.
+fn case_fold(s: &str) -> String {
+    s.to_uppercase().to_lowercase()
+}
+
+// Native function for case-insensitive equality: (string/case-fold-eq? a b)
+fn case_fold_eq(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/case-fold-eq?");
+    if args.len() != 2 {
+        let msg = format!(
+            "string/case-fold-eq? expects 2 arguments, got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let a = extract_string(&args[0], "string/case-fold-eq?")?;
+    let b = extract_string(&args[1], "string/case-fold-eq?")?;
+    Ok(Expr::Bool(case_fold(&a) == case_fold(&b)))
+}
+
+// Native function for counting non-overlapping occurrences: (string/count-matches needle s)
+fn count_matches(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/count-matches");
+    if args.len() != 2 {
+        let msg = format!(
+            "string/count-matches expects 2 arguments, got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let needle = extract_string(&args[0], "string/count-matches")?;
+    let s = extract_string(&args[1], "string/count-matches")?;
+    // An empty needle would "match" between every pair of characters.
+    if needle.is_empty() {
+        let msg = "string/count-matches expects a non-empty needle".to_string();
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    Ok(Expr::Number(s.matches(&needle).count() as f64))
+}
+
+// Native function for trimming leading whitespace: (string/trim-left s)
+fn trim_left(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/trim-left");
+    if args.len() != 1 {
+        let msg = format!("string/trim-left expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/trim-left")?;
+    Ok(Expr::String(s.trim_start().to_string()))
+}
+
+// Native function for trimming trailing whitespace: (string/trim-right s)
+fn trim_right(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/trim-right");
+    if args.len() != 1 {
+        let msg = format!("string/trim-right expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/trim-right")?;
+    Ok(Expr::String(s.trim_end().to_string()))
+}
+
+// Native function for rendering a duration: (string/format-duration seconds)
+// Rounds to whole seconds and drops zero leading units: 3723 => "1h 2m 3s", 45 => "45s".
+fn format_duration(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/format-duration");
+    if args.len() != 1 {
+        let msg = format!(
+            "string/format-duration expects 1 argument, got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let seconds = match &args[0] {
+        Expr::Number(n) => *n,
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native string function");
+            return Err(type_error);
+        }
+    };
+    if seconds < 0.0 || !seconds.is_finite() {
+        let msg = format!(
+            "string/format-duration expects a non-negative finite number of seconds, got {}",
+            seconds
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+
+    // `as` saturates, so reject anything past u64::MAX (2^64 as f64) rather than print garbage.
+    let rounded = seconds.round();
+    if rounded >= u64::MAX as f64 {
+        let msg = format!(
+            "string/format-duration: {} seconds is too large to format",
+            seconds
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    let total = rounded as u64;
+    let (hours, minutes, secs) = (total / 3600, total / 60 % 60, total % 60);
+    let formatted = if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, secs)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    };
+    Ok(Expr::String(formatted))
+}
+
+// Native function for checking a string against a set of characters:
+// (string/contains-any? s char-set)
+fn contains_any(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native string function: string/contains-any?");
+    if args.len() != 2 {
+        let msg = format!(
+            "string/contains-any? expects 2 arguments, got {}",
+            args.len()
+        );
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    let s = extract_string(&args[0], "string/contains-any?")?;
+    let char_set = extract_string(&args[1], "string/contains-any?")?;
+    Ok(Expr::Bool(s.chars().any(|c| char_set.contains(c))))
+}
 
 /// Creates the `string` module with its associated functions.
 pub fn create_string_module() -> Expr {
@@ -199,6 +723,132 @@ pub fn create_string_module() -> Expr {
                     func: string_format,
                 }),
             ),
+            (
+                "parse-int".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/parse-int".to_string(),
+                    func: parse_int,
+                }),
+            ),
+            (
+                "format-int".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/format-int".to_string(),
+                    func: format_int,
+                }),
+            ),
+            (
+                "split".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/split".to_string(),
+                    func: split,
+                }),
+            ),
+            (
+                "char-code".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/char-code".to_string(),
+                    func: char_code,
+                }),
+            ),
+            (
+                "code-char".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/code-char".to_string(),
+                    func: code_char,
+                }),
+            ),
+            (
+                "format-positional".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/format-positional".to_string(),
+                    func: format_positional,
+                }),
+            ),
+            (
+                "parse-float".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/parse-float".to_string(),
+                    func: parse_float,
+                }),
+            ),
+            (
+                "replace-first".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/replace-first".to_string(),
+                    func: replace_first,
+                }),
+            ),
+            (
+                "capitalize".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/capitalize".to_string(),
+                    func: capitalize,
+                }),
+            ),
+            (
+                "title-case".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/title-case".to_string(),
+                    func: title_case,
+                }),
+            ),
+            (
+                "index-of".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/index-of".to_string(),
+                    func: index_of,
+                }),
+            ),
+            (
+                "last-index-of".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/last-index-of".to_string(),
+                    func: last_index_of,
+                }),
+            ),
+            (
+                "case-fold-eq?".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/case-fold-eq?".to_string(),
+                    func: case_fold_eq,
+                }),
+            ),
+            (
+                "count-matches".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/count-matches".to_string(),
+                    func: count_matches,
+                }),
+            ),
+            (
+                "trim-left".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/trim-left".to_string(),
+                    func: trim_left,
+                }),
+            ),
+            (
+                "trim-right".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/trim-right".to_string(),
+                    func: trim_right,
+                }),
+            ),
+            (
+                "format-duration".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/format-duration".to_string(),
+                    func: format_duration,
+                }),
+            ),
+            (
+                "contains-any?".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "string/contains-any?".to_string(),
+                    func: contains_any,
+                }),
+            ),
         ]);
 
         for (name, func_expr) in functions_to_define {
@@ -412,4 +1062,550 @@ mod tests {
         let err_type = eval_str(r#"(string.format 123 "arg")"#, env).unwrap_err();
         assert!(matches!(err_type, LispError::TypeError { expected, .. } if expected == "String (for format)"));
     }
+
+    #[test]
+    fn test_string_parse_int() {
+        let env = env_with_testable_string_functions();
+
+        // Decimal is the default radix
+        let result = eval_str(r#"(string.parse-int "42")"#, env.clone()).unwrap();
+        assert_eq!(result, Expr::Number(42.0));
+
+        let result_negative = eval_str(r#"(string.parse-int "-17")"#, env.clone()).unwrap();
+        assert_eq!(result_negative, Expr::Number(-17.0));
+
+        // Hexadecimal
+        let result_hex = eval_str(r#"(string.parse-int "ff" 16)"#, env.clone()).unwrap();
+        assert_eq!(result_hex, Expr::Number(255.0));
+
+        let result_hex_upper = eval_str(r#"(string.parse-int "1A" 16)"#, env.clone()).unwrap();
+        assert_eq!(result_hex_upper, Expr::Number(26.0));
+
+        // Binary
+        let result_bin = eval_str(r#"(string.parse-int "1011" 2)"#, env.clone()).unwrap();
+        assert_eq!(result_bin, Expr::Number(11.0));
+
+        // Malformed input
+        let err_malformed = eval_str(r#"(string.parse-int "12abc")"#, env.clone()).unwrap_err();
+        assert!(matches!(err_malformed, LispError::ValueError(_)));
+
+        let err_bad_digit = eval_str(r#"(string.parse-int "102" 2)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_bad_digit, LispError::ValueError(_)));
+
+        let err_empty = eval_str(r#"(string.parse-int "")"#, env.clone()).unwrap_err();
+        assert!(matches!(err_empty, LispError::ValueError(_)));
+
+        // Invalid radix
+        let err_radix = eval_str(r#"(string.parse-int "10" 37)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_radix, LispError::ValueError(_)));
+
+        let err_radix_fraction =
+            eval_str(r#"(string.parse-int "10" 2.5)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_radix_fraction, LispError::ValueError(_)));
+
+        // Arity and type errors
+        let err_arity = eval_str(r#"(string.parse-int)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_arity, LispError::ArityMismatch(_)));
+
+        let err_type = eval_str(r#"(string.parse-int 10)"#, env).unwrap_err();
+        assert!(matches!(err_type, LispError::TypeError { expected, .. } if expected == "String"));
+    }
+
+    #[test]
+    fn test_string_format_int() {
+        let env = env_with_testable_string_functions();
+
+        // Hexadecimal
+        let result_hex = eval_str(r#"(string.format-int 255 16)"#, env.clone()).unwrap();
+        assert_eq!(result_hex, Expr::String("ff".to_string()));
+
+        // Binary
+        let result_bin = eval_str(r#"(string.format-int 11 2)"#, env.clone()).unwrap();
+        assert_eq!(result_bin, Expr::String("1011".to_string()));
+
+        // Zero and negative numbers
+        let result_zero = eval_str(r#"(string.format-int 0 2)"#, env.clone()).unwrap();
+        assert_eq!(result_zero, Expr::String("0".to_string()));
+
+        let result_negative = eval_str(r#"(string.format-int -26 16)"#, env.clone()).unwrap();
+        assert_eq!(result_negative, Expr::String("-1a".to_string()));
+
+        // Round-trips with parse-int
+        let result_round_trip = eval_str(
+            r#"(string.parse-int (string.format-int 1234 36) 36)"#,
+            env.clone(),
+        )
+        .unwrap();
+        assert_eq!(result_round_trip, Expr::Number(1234.0));
+
+        // Invalid radix
+        let err_radix_low = eval_str(r#"(string.format-int 10 1)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_radix_low, LispError::ValueError(_)));
+
+        let err_radix_high = eval_str(r#"(string.format-int 10 37)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_radix_high, LispError::ValueError(_)));
+
+        // Non-integral input
+        let err_fraction = eval_str(r#"(string.format-int 1.5 10)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_fraction, LispError::ValueError(_)));
+
+        // Out of i64 range: 2^63 is rejected, -2^63 is i64::MIN
+        let err_too_large =
+            eval_str(r#"(string.format-int 9223372036854775808 16)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_too_large, LispError::ValueError(_)));
+
+        let result_min = eval_str(
+            r#"(string.format-int -9223372036854775808 16)"#,
+            env.clone(),
+        )
+        .unwrap();
+        assert_eq!(result_min, Expr::String("-8000000000000000".to_string()));
+
+        // Arity and type errors
+        let err_arity = eval_str(r#"(string.format-int 10)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_arity, LispError::ArityMismatch(_)));
+
+        let err_type = eval_str(r#"(string.format-int "10" 2)"#, env).unwrap_err();
+        assert!(matches!(err_type, LispError::TypeError { .. }));
+    }
+
+    #[test]
+    fn test_string_split() {
+        let env = env_with_testable_string_functions();
+        let strings = |parts: &[&str]| {
+            Expr::List(
+                parts
+                    .iter()
+                    .map(|part| Expr::String(part.to_string()))
+                    .collect(),
+            )
+        };
+
+        let result = eval_str(r#"(string.split "a,b,c" ",")"#, env.clone()).unwrap();
+        assert_eq!(result, strings(&["a", "b", "c"]));
+
+        // Multi-character separators
+        let result_multi = eval_str(r#"(string.split "a::b" "::")"#, env.clone()).unwrap();
+        assert_eq!(result_multi, strings(&["a", "b"]));
+
+        // Trailing empty fields are kept by default...
+        let result_default = eval_str(r#"(string.split "a,b," ",")"#, env.clone()).unwrap();
+        assert_eq!(result_default, strings(&["a", "b", ""]));
+
+        let result_keep = eval_str(r#"(string.split "a,b," "," true)"#, env.clone()).unwrap();
+        assert_eq!(result_keep, strings(&["a", "b", ""]));
+
+        // ...and dropped when keep-empty is false. Interior empty fields are unaffected.
+        let result_drop = eval_str(r#"(string.split "a,,b,," "," false)"#, env.clone()).unwrap();
+        assert_eq!(result_drop, strings(&["a", "", "b"]));
+
+        let result_lines = eval_str("(string.split \"x\ny\n\" \"\n\" false)", env.clone()).unwrap();
+        assert_eq!(result_lines, strings(&["x", "y"]));
+
+        // Errors
+        let err_empty_sep = eval_str(r#"(string.split "abc" "")"#, env.clone()).unwrap_err();
+        assert!(matches!(err_empty_sep, LispError::ValueError(_)));
+
+        let err_flag = eval_str(r#"(string.split "a,b" "," "no")"#, env.clone()).unwrap_err();
+        assert!(matches!(err_flag, LispError::TypeError { .. }));
+
+        let err_arity = eval_str(r#"(string.split "a,b")"#, env).unwrap_err();
+        assert!(matches!(err_arity, LispError::ArityMismatch(_)));
+    }
+
+    #[test]
+    fn test_string_char_code_and_code_char() {
+        let env = env_with_testable_string_functions();
+
+        // ASCII
+        let result_code = eval_str(r#"(string.char-code "A")"#, env.clone()).unwrap();
+        assert_eq!(result_code, Expr::Number(65.0));
+
+        let result_char = eval_str(r#"(string.code-char 97)"#, env.clone()).unwrap();
+        assert_eq!(result_char, Expr::String("a".to_string()));
+
+        // Non-ASCII
+        let result_code_unicode = eval_str(r#"(string.char-code "λ")"#, env.clone()).unwrap();
+        assert_eq!(result_code_unicode, Expr::Number(955.0));
+
+        let result_char_unicode = eval_str(r#"(string.code-char 128512)"#, env.clone()).unwrap();
+        assert_eq!(result_char_unicode, Expr::String("😀".to_string()));
+
+        // Round trip
+        let result_round_trip =
+            eval_str(r#"(string.code-char (string.char-code "é"))"#, env.clone()).unwrap();
+        assert_eq!(result_round_trip, Expr::String("é".to_string()));
+
+        // Invalid code points
+        let err_surrogate = eval_str(r#"(string.code-char 55296)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_surrogate, LispError::ValueError(_)));
+
+        let err_too_large = eval_str(r#"(string.code-char 1114112)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_too_large, LispError::ValueError(_)));
+
+        let err_negative = eval_str(r#"(string.code-char -1)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_negative, LispError::ValueError(_)));
+
+        let err_fraction = eval_str(r#"(string.code-char 65.5)"#, env.clone()).unwrap_err();
+        assert!(matches!(err_fraction, LispError::ValueError(_)));
+
+        // char-code needs exactly one character
+        let err_multi = eval_str(r#"(string.char-code "ab")"#, env.clone()).unwrap_err();
+        assert!(matches!(err_multi, LispError::ValueError(_)));
+
+        let err_empty = eval_str(r#"(string.char-code "")"#, env.clone()).unwrap_err();
+        assert!(matches!(err_empty, LispError::ValueError(_)));
+
+        let err_type = eval_str(r#"(string.code-char "A")"#, env).unwrap_err();
+        assert!(matches!(err_type, LispError::TypeError { .. }));
+    }
+
+    #[test]
+    fn test_string_format_positional_reorders_and_reuses() {
+        let env = env_with_testable_string_functions();
+        let result = eval_str(
+            r#"(string.format-positional "{1} {0}" "world" "hello")"#,
+            env.clone(),
+        )
+        .unwrap();
+        assert_eq!(result, Expr::String("hello world".to_string()));
+
+        let result_reuse = eval_str(
+            r#"(string.format-positional "{0} {1} {0}" 1 "and")"#,
+            env.clone(),
+        )
+        .unwrap();
+        assert_eq!(result_reuse, Expr::String("1 and 1".to_string()));
+    }
+
+    #[test]
+    fn test_string_format_positional_leaves_other_braces() {
+        let env = env_with_testable_string_functions();
+        let result = eval_str(
+            r#"(string.format-positional "{x} {} {0} {" "a")"#,
+            env.clone(),
+        )
+        .unwrap();
+        assert_eq!(result, Expr::String("{x} {} a {".to_string()));
+    }
+
+    #[test]
+    fn test_string_format_positional_errors() {
+        let env = env_with_testable_string_functions();
+        let result_out_of_range =
+            eval_str(r#"(string.format-positional "{2}" "a" "b")"#, env.clone());
+        assert!(matches!(result_out_of_range, Err(LispError::ValueError(_))));
+
+        let result_type = eval_str("(string.format-positional 5)", env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str("(string.format-positional)", env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_parse_float_valid() {
+        let env = env_with_testable_string_functions();
+        let cases = [
+            ("1.5", 1.5),
+            ("-0.25", -0.25),
+            ("42", 42.0),
+            (".5", 0.5),
+            ("1e3", 1000.0),
+            ("2.5E-2", 0.025),
+        ];
+        for (input, expected) in cases {
+            let result = eval_str(&format!(r#"(string.parse-float "{}")"#, input), env.clone());
+            assert_eq!(result, Ok(Expr::Number(expected)), "input {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_string_parse_float_invalid() {
+        let env = env_with_testable_string_functions();
+        for input in ["1,5", "", " 1.5", "1.5abc", "abc"] {
+            let result = eval_str(&format!(r#"(string.parse-float "{}")"#, input), env.clone());
+            assert!(
+                matches!(result, Err(LispError::ValueError(_))),
+                "input {:?} gave {:?}",
+                input,
+                result
+            );
+        }
+
+        let result_type = eval_str("(string.parse-float 1.5)", env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str("(string.parse-float)", env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_replace_first() {
+        let env = env_with_testable_string_functions();
+        let result = eval_str(
+            r#"(string.replace-first "cat" "dog" "a cat sat")"#,
+            env.clone(),
+        );
+        assert_eq!(result, Ok(Expr::String("a dog sat".to_string())));
+
+        // Only the first of several occurrences is replaced.
+        let result_multiple =
+            eval_str(r#"(string.replace-first "ab" "X" "ab-ab-ab")"#, env.clone());
+        assert_eq!(result_multiple, Ok(Expr::String("X-ab-ab".to_string())));
+
+        let result_no_match = eval_str(r#"(string.replace-first "zz" "X" "hello")"#, env.clone());
+        assert_eq!(result_no_match, Ok(Expr::String("hello".to_string())));
+    }
+
+    #[test]
+    fn test_string_replace_first_errors() {
+        let env = env_with_testable_string_functions();
+        let result_type = eval_str(r#"(string.replace-first "a" "b" 5)"#, env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str(r#"(string.replace-first "a" "b")"#, env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_capitalize() {
+        let env = env_with_testable_string_functions();
+        let result = eval_str(r#"(string.capitalize "hELLO wORLD")"#, env.clone());
+        assert_eq!(result, Ok(Expr::String("Hello world".to_string())));
+
+        let result_multibyte = eval_str(r#"(string.capitalize "éCOLE")"#, env.clone());
+        assert_eq!(result_multibyte, Ok(Expr::String("École".to_string())));
+
+        let result_empty = eval_str(r#"(string.capitalize "")"#, env.clone());
+        assert_eq!(result_empty, Ok(Expr::String(String::new())));
+    }
+
+    #[test]
+    fn test_string_title_case() {
+        let env = env_with_testable_string_functions();
+        let result = eval_str(
+            r#"(string.title-case "the QUICK  brown\tfox")"#,
+            env.clone(),
+        );
+        assert_eq!(
+            result,
+            Ok(Expr::String("The Quick  Brown\tFox".to_string()))
+        );
+
+        let result_multibyte = eval_str(r#"(string.title-case "über élan")"#, env.clone());
+        assert_eq!(result_multibyte, Ok(Expr::String("Über Élan".to_string())));
+
+        let result_empty = eval_str(r#"(string.title-case "")"#, env.clone());
+        assert_eq!(result_empty, Ok(Expr::String(String::new())));
+    }
+
+    #[test]
+    fn test_string_capitalize_title_case_errors() {
+        let env = env_with_testable_string_functions();
+        let result_type = eval_str("(string.capitalize 5)", env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str(r#"(string.title-case "a" "b")"#, env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_index_of_and_last_index_of() {
+        let env = env_with_testable_string_functions();
+        // Multiple occurrences: first and last differ.
+        let first = eval_str(r#"(string.index-of "a/b/c" "/")"#, env.clone());
+        assert_eq!(first, Ok(Expr::Number(1.0)));
+        let last = eval_str(r#"(string.last-index-of "a/b/c" "/")"#, env.clone());
+        assert_eq!(last, Ok(Expr::Number(3.0)));
+
+        // A single occurrence: both agree.
+        let first_once = eval_str(r#"(string.index-of "key=value" "=")"#, env.clone());
+        assert_eq!(first_once, Ok(Expr::Number(3.0)));
+        let last_once = eval_str(r#"(string.last-index-of "key=value" "=")"#, env.clone());
+        assert_eq!(last_once, Ok(Expr::Number(3.0)));
+
+        // Not present at all.
+        let first_missing = eval_str(r#"(string.index-of "abc" "z")"#, env.clone());
+        assert_eq!(first_missing, Ok(Expr::Nil));
+        let last_missing = eval_str(r#"(string.last-index-of "abc" "z")"#, env.clone());
+        assert_eq!(last_missing, Ok(Expr::Nil));
+    }
+
+    #[test]
+    fn test_string_last_index_of_counts_chars_not_bytes() {
+        let env = env_with_testable_string_functions();
+        let result = eval_str(r#"(string.last-index-of "é.é.é" "é")"#, env.clone());
+        assert_eq!(result, Ok(Expr::Number(4.0)));
+    }
+
+    #[test]
+    fn test_string_index_of_errors() {
+        let env = env_with_testable_string_functions();
+        let result_type = eval_str(r#"(string.last-index-of "abc" 1)"#, env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str(r#"(string.index-of "abc")"#, env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_case_fold_eq() {
+        let env = env_with_testable_string_functions();
+        let equal = eval_str(
+            r#"(string.case-fold-eq? "Hello World" "hELLO wORLD")"#,
+            env.clone(),
+        );
+        assert_eq!(equal, Ok(Expr::Bool(true)));
+
+        let unequal = eval_str(r#"(string.case-fold-eq? "Hello" "Help")"#, env.clone());
+        assert_eq!(unequal, Ok(Expr::Bool(false)));
+
+        let non_ascii = eval_str(r#"(string.case-fold-eq? "ÉCOLE" "école")"#, env.clone());
+        assert_eq!(non_ascii, Ok(Expr::Bool(true)));
+    }
+
+    #[test]
+    fn test_string_case_fold_eq_expands_sharp_s() {
+        let env = env_with_testable_string_functions();
+        // Full case folding treats `ß` as "ss", so lengths may differ between equal strings.
+        let result = eval_str(r#"(string.case-fold-eq? "straße" "STRASSE")"#, env.clone());
+        assert_eq!(result, Ok(Expr::Bool(true)));
+    }
+
+    #[test]
+    fn test_string_case_fold_eq_errors() {
+        let env = env_with_testable_string_functions();
+        let result_type = eval_str(r#"(string.case-fold-eq? "a" 1)"#, env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str(r#"(string.case-fold-eq? "a")"#, env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_count_matches() {
+        let env = env_with_testable_string_functions();
+        let multiple = eval_str(r#"(string.count-matches "ab" "ab-ab-ab")"#, env.clone());
+        assert_eq!(multiple, Ok(Expr::Number(3.0)));
+
+        // Occurrences do not overlap.
+        let overlapping = eval_str(r#"(string.count-matches "aa" "aaaa")"#, env.clone());
+        assert_eq!(overlapping, Ok(Expr::Number(2.0)));
+
+        let none = eval_str(r#"(string.count-matches "z" "abc")"#, env.clone());
+        assert_eq!(none, Ok(Expr::Number(0.0)));
+    }
+
+    #[test]
+    fn test_string_count_matches_errors() {
+        let env = env_with_testable_string_functions();
+        let result_empty = eval_str(r#"(string.count-matches "" "abc")"#, env.clone());
+        assert!(matches!(result_empty, Err(LispError::ValueError(_))));
+
+        let result_type = eval_str(r#"(string.count-matches 1 "abc")"#, env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str(r#"(string.count-matches "a")"#, env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_trim_left() {
+        let env = env_with_testable_string_functions();
+        let leading = eval_str(r#"(string.trim-left "  hi")"#, env.clone());
+        assert_eq!(leading, Ok(Expr::String("hi".to_string())));
+
+        let trailing = eval_str(r#"(string.trim-left "hi  ")"#, env.clone());
+        assert_eq!(trailing, Ok(Expr::String("hi  ".to_string())));
+
+        let both = eval_str("(string.trim-left \"\\t hi \\n\")", env.clone());
+        assert_eq!(both, Ok(Expr::String("hi \n".to_string())));
+    }
+
+    #[test]
+    fn test_string_trim_right() {
+        let env = env_with_testable_string_functions();
+        let leading = eval_str(r#"(string.trim-right "  hi")"#, env.clone());
+        assert_eq!(leading, Ok(Expr::String("  hi".to_string())));
+
+        let trailing = eval_str(r#"(string.trim-right "hi  ")"#, env.clone());
+        assert_eq!(trailing, Ok(Expr::String("hi".to_string())));
+
+        let both = eval_str("(string.trim-right \"\\t hi \\n\")", env.clone());
+        assert_eq!(both, Ok(Expr::String("\t hi".to_string())));
+    }
+
+    #[test]
+    fn test_string_trim_left_and_right_errors() {
+        let env = env_with_testable_string_functions();
+        let result_type = eval_str(r#"(string.trim-left 1)"#, env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str(r#"(string.trim-right "a" "b")"#, env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_format_duration() {
+        let env = env_with_testable_string_functions();
+        let sub_minute = eval_str("(string.format-duration 45)", env.clone());
+        assert_eq!(sub_minute, Ok(Expr::String("45s".to_string())));
+
+        let zero = eval_str("(string.format-duration 0)", env.clone());
+        assert_eq!(zero, Ok(Expr::String("0s".to_string())));
+
+        let minutes = eval_str("(string.format-duration 125)", env.clone());
+        assert_eq!(minutes, Ok(Expr::String("2m 5s".to_string())));
+
+        let multi_unit = eval_str("(string.format-duration 3723)", env.clone());
+        assert_eq!(multi_unit, Ok(Expr::String("1h 2m 3s".to_string())));
+
+        // Only leading zero units are dropped.
+        let inner_zero = eval_str("(string.format-duration 3601)", env.clone());
+        assert_eq!(inner_zero, Ok(Expr::String("1h 0m 1s".to_string())));
+
+        let fractional = eval_str("(string.format-duration 59.6)", env.clone());
+        assert_eq!(fractional, Ok(Expr::String("1m 0s".to_string())));
+    }
+
+    #[test]
+    fn test_string_format_duration_errors() {
+        let env = env_with_testable_string_functions();
+        let result_negative = eval_str("(string.format-duration -1)", env.clone());
+        assert!(matches!(result_negative, Err(LispError::ValueError(_))));
+
+        let result_too_large = eval_str("(string.format-duration 1e300)", env.clone());
+        assert!(matches!(result_too_large, Err(LispError::ValueError(_))));
+
+        let result_type = eval_str(r#"(string.format-duration "60")"#, env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str("(string.format-duration)", env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_string_contains_any() {
+        let env = env_with_testable_string_functions();
+        let hit = eval_str(r#"(string.contains-any? "user@host" "@:/")"#, env.clone());
+        assert_eq!(hit, Ok(Expr::Bool(true)));
+
+        let miss = eval_str(r#"(string.contains-any? "plain" "@:/")"#, env.clone());
+        assert_eq!(miss, Ok(Expr::Bool(false)));
+
+        // An empty character set has nothing to find.
+        let empty_set = eval_str(r#"(string.contains-any? "abc" "")"#, env.clone());
+        assert_eq!(empty_set, Ok(Expr::Bool(false)));
+    }
+
+    #[test]
+    fn test_string_contains_any_errors() {
+        let env = env_with_testable_string_functions();
+        let result_type = eval_str(r#"(string.contains-any? "abc" 1)"#, env.clone());
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_str(r#"(string.contains-any? "abc")"#, env.clone());
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
 }
@@ -0,0 +1,614 @@
+use crate::MODULE_CACHE;
+use crate::engine::ast::{Expr, LispModule};
+use crate::engine::builtins::globals::BUILTIN_MODULES;
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, calling_env};
+use crate::engine::special_forms::is_special_form;
+use std::cell::RefCell;
+use std::io::BufRead;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::{error, trace};
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_sleep(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'sleep' function");
+    if args.len() != 1 {
+        let msg = format!("sleep expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let seconds = match &args[0] {
+        Expr::Number(n) => *n,
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native 'sleep'");
+            return Err(type_error);
+        }
+    };
+
+    // try_from_secs_f64 rejects negative, non-finite and too-large values instead of panicking.
+    let duration = Duration::try_from_secs_f64(seconds).map_err(|e| {
+        let msg = format!(
+            "sleep expects a non-negative, finite number of seconds, got {} ({})",
+            seconds, e
+        );
+        error!("{}", msg);
+        LispError::ValueError(msg)
+    })?;
+
+    std::thread::sleep(duration);
+    Ok(Expr::Nil)
+}
+
+/// The interpreter version, taken from the crate manifest at compile time.
+pub const RSP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_rsp_version(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'rsp-version' function");
+    if !args.is_empty() {
+        let msg = format!("rsp-version expects 0 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    Ok(Expr::String(RSP_VERSION.to_string()))
+}
+
+thread_local! {
+    // Optional override for `read-line` input on this thread. When unset, stdin is used.
+    static INPUT_READER: RefCell<Option<Box<dyn BufRead>>> = const { RefCell::new(None) };
+}
+
+/// Makes `read-line` on the current thread read from `reader` instead of stdin.
+/// Passing `None` restores stdin. Returns the previously installed reader.
+pub fn set_input_reader(reader: Option<Box<dyn BufRead>>) -> Option<Box<dyn BufRead>> {
+    INPUT_READER.with(|cell| cell.replace(reader))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_read_line(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'read-line' function");
+    if !args.is_empty() {
+        let msg = format!("read-line expects 0 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let mut line = String::new();
+    let read_result = INPUT_READER.with(|cell| match cell.borrow_mut().as_mut() {
+        Some(reader) => reader.read_line(&mut line),
+        None => std::io::stdin().lock().read_line(&mut line),
+    });
+    match read_result {
+        Ok(0) => Ok(Expr::Nil), // EOF
+        Ok(_) => {
+            let trimmed_len = line.trim_end_matches(['\n', '\r']).len();
+            line.truncate(trimmed_len);
+            Ok(Expr::String(line))
+        }
+        Err(e) => {
+            let msg = format!("read-line failed to read input: {}", e);
+            error!("{}", msg);
+            Err(LispError::Evaluation(msg))
+        }
+    }
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_modules(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'modules' function");
+    if !args.is_empty() {
+        let msg = format!("modules expects 0 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    // Built-in modules first, then file modules loaded via `require` on this thread.
+    let mut loaded_paths: Vec<String> = MODULE_CACHE.with(|cache_cell| {
+        cache_cell
+            .borrow()
+            .keys()
+            .map(|path| path.display().to_string())
+            .collect()
+    });
+    loaded_paths.sort();
+
+    let names = BUILTIN_MODULES
+        .iter()
+        .map(|name| format!("builtin:{}", name))
+        .chain(loaded_paths)
+        .map(Expr::String)
+        .collect();
+    Ok(Expr::List(names))
+}
+
+// Helper function to borrow the module behind a module argument.
+fn extract_module<'a>(expr: &'a Expr, op_name: &str) -> Result<&'a LispModule, LispError> {
+    match expr {
+        Expr::Module(module) => Ok(module),
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Module".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native module function");
+            Err(type_error)
+        }
+    }
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_merge_modules(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'merge-modules' function");
+    if args.len() != 2 {
+        let msg = format!("merge-modules expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let modules = [
+        extract_module(&args[0], "merge-modules")?,
+        extract_module(&args[1], "merge-modules")?,
+    ];
+
+    // Copy the left module's bindings first so the right one wins on conflicts.
+    let merged_env = Environment::new();
+    for module in &modules {
+        for (name, value) in module.env.borrow().get_all_bindings() {
+            merged_env.borrow_mut().define(name, value);
+        }
+    }
+    Ok(Expr::Module(LispModule {
+        path: PathBuf::from(format!(
+            "merged:{}+{}",
+            modules[0].path.display(),
+            modules[1].path.display()
+        )),
+        env: merged_env,
+    }))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_module_members(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'module-members' function");
+    if args.len() != 1 {
+        let msg = format!("module-members expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let module = extract_module(&args[0], "module-members")?;
+    let mut names: Vec<String> = module
+        .env
+        .borrow()
+        .get_all_bindings()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    names.sort();
+    Ok(Expr::List(names.into_iter().map(Expr::String).collect()))
+}
+
+// Helper function to read a binding name, given as a string or a (quoted) symbol.
+fn extract_binding_name(expr: &Expr, op_name: &str) -> Result<String, LispError> {
+    match expr {
+        Expr::String(name) | Expr::Symbol(name) => Ok(name.clone()),
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "String".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native binding function");
+            Err(type_error)
+        }
+    }
+}
+
+// Helper function to reach the environment a reflective native was called from.
+fn extract_calling_env(op_name: &str) -> Result<Rc<RefCell<Environment>>, LispError> {
+    calling_env().ok_or_else(|| {
+        let msg = format!("{} must be called from Lisp code", op_name);
+        error!("{}", msg);
+        LispError::Evaluation(msg)
+    })
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_env_get(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'env-get' function");
+    if args.len() != 1 {
+        let msg = format!("env-get expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let name = extract_binding_name(&args[0], "env-get")?;
+    let env = extract_calling_env("env-get")?;
+    let value = env.borrow().get(&name).unwrap_or(Expr::Nil);
+    Ok(value)
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_env_set(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'env-set' function");
+    if args.len() != 2 {
+        let msg = format!("env-set expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let name = extract_binding_name(&args[0], "env-set")?;
+    if is_special_form(&name) {
+        error!(attempted_keyword = %name, "Attempted to bind a reserved keyword using 'env-set'");
+        return Err(LispError::ReservedKeyword(name));
+    }
+    let env = extract_calling_env("env-set")?;
+    let value = args[1].clone();
+    env.borrow_mut().define(name, value.clone());
+    Ok(value)
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_debug(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'debug' function");
+    if args.len() != 1 {
+        let msg = format!("debug expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    // Printed to stderr so it never mixes with a program's own output.
+    let value = args.into_iter().next().unwrap_or(Expr::Nil);
+    eprintln!("{:?}", value);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::eval;
+    use crate::engine::parser::parse_expr;
+    use crate::logging::init_test_logging;
+
+    // Helper to evaluate a single Lisp expression in a fresh prelude environment.
+    fn eval_system_str(code: &str) -> Result<Expr, LispError> {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let (remaining, parsed_expr_option) = parse_expr(code)
+            .unwrap_or_else(|e| panic!("Test parse error for code '{}': {}", code, e));
+        assert!(
+            remaining.is_empty(),
+            "Unexpected remaining input after parsing in test for code '{}': {}",
+            code,
+            remaining
+        );
+        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
+        eval(&parsed_expr, env)
+    }
+
+    #[test]
+    fn test_native_sleep_returns_nil() {
+        let result = eval_system_str("(sleep 0.001)").unwrap();
+        assert_eq!(result, Expr::Nil);
+    }
+
+    #[test]
+    fn test_native_sleep_negative_duration_error() {
+        let result = eval_system_str("(sleep -1)");
+        assert!(matches!(result, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_sleep_huge_duration_error() {
+        let result = eval_system_str("(sleep 1e300)");
+        assert!(matches!(result, Err(LispError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_native_sleep_type_error() {
+        let result = eval_system_str("(sleep \"1\")");
+        assert!(matches!(result, Err(LispError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_native_sleep_arity_error() {
+        let result = eval_system_str("(sleep)");
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_rsp_version() {
+        let result = eval_system_str("(rsp-version)").unwrap();
+        match result {
+            Expr::String(version) => {
+                assert!(!version.is_empty());
+                assert_eq!(version, env!("CARGO_PKG_VERSION"));
+            }
+            other => panic!("Expected a version string, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_native_rsp_version_arity_error() {
+        let result = eval_system_str("(rsp-version 1)");
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_env_set_then_symbol_lookup() {
+        let result = eval_system_str(r#"(begin (env-set "answer" (+ 40 2)) answer)"#);
+        assert_eq!(result, Ok(Expr::Number(42.0)));
+    }
+
+    #[test]
+    fn test_native_env_get_returns_bound_value_or_nil() {
+        let result = eval_system_str(r#"(begin (let x 10) (env-get "x"))"#);
+        assert_eq!(result, Ok(Expr::Number(10.0)));
+        let result = eval_system_str("(begin (let x 10) (env-get 'x))");
+        assert_eq!(result, Ok(Expr::Number(10.0)));
+        assert_eq!(eval_system_str(r#"(env-get "missing")"#), Ok(Expr::Nil));
+    }
+
+    #[test]
+    fn test_native_env_get_sees_caller_bindings() {
+        // Inside a function body the calling environment holds the parameters.
+        let result = eval_system_str(r#"((fn (secret) (env-get "secret")) 7)"#);
+        assert_eq!(result, Ok(Expr::Number(7.0)));
+        // Applied by another native, it sees the environment of that outer call.
+        let result = eval_system_str(r#"(begin (let a 1) ((partial env-get "a")))"#);
+        assert_eq!(result, Ok(Expr::Number(1.0)));
+    }
+
+    #[test]
+    fn test_native_env_set_rejects_reserved_keywords() {
+        let result = eval_system_str(r#"(env-set "let" 1)"#);
+        assert_eq!(result, Err(LispError::ReservedKeyword("let".to_string())));
+    }
+
+    #[test]
+    fn test_native_env_get_and_env_set_errors() {
+        assert!(matches!(
+            eval_system_str("(env-get)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_system_str(r#"(env-set "x")"#),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_system_str("(env-set 1 2)"),
+            Err(LispError::TypeError { .. })
+        ));
+        // Called directly from Rust there is no calling environment.
+        assert!(matches!(
+            native_env_get(vec![Expr::String("x".to_string())]),
+            Err(LispError::Evaluation(_))
+        ));
+    }
+
+    // Evaluates `(read-line)` repeatedly against an in-memory reader.
+    fn read_lines_from(input: &'static str, count: usize) -> Vec<Result<Expr, LispError>> {
+        let previous = set_input_reader(Some(Box::new(std::io::Cursor::new(input))));
+        let results = (0..count).map(|_| eval_system_str("(read-line)")).collect();
+        set_input_reader(previous);
+        results
+    }
+
+    #[test]
+    fn test_native_read_line_reads_lines_then_eof() {
+        let results = read_lines_from("first\nsecond\r\nlast", 4);
+        assert_eq!(
+            results,
+            vec![
+                Ok(Expr::String("first".to_string())),
+                Ok(Expr::String("second".to_string())),
+                Ok(Expr::String("last".to_string())),
+                Ok(Expr::Nil),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_native_read_line_empty_line_is_not_eof() {
+        let results = read_lines_from("\n", 2);
+        assert_eq!(
+            results,
+            vec![Ok(Expr::String(String::new())), Ok(Expr::Nil)]
+        );
+    }
+
+    #[test]
+    fn test_native_read_line_arity_error() {
+        let result = eval_system_str("(read-line 1)");
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_modules_lists_builtins() {
+        let result = eval_system_str("(modules)").unwrap();
+        let Expr::List(names) = result else {
+            panic!("Expected a list of module names, got {:?}", result);
+        };
+        for name in [
+            "builtin:math",
+            "builtin:log",
+            "builtin:string",
+            "builtin:list",
+            "builtin:map",
+            "builtin:json",
+            "builtin:csv",
+            "builtin:vector",
+        ] {
+            assert!(
+                names.contains(&Expr::String(name.to_string())),
+                "missing {}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_native_modules_includes_required_module() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("listed_module.lisp");
+        std::fs::write(&file_path, "(let x 1)").unwrap();
+        let canonical_path = std::fs::canonicalize(&file_path).unwrap();
+
+        eval_system_str(&format!("(require \"{}\")", file_path.display())).unwrap();
+        let result = eval_system_str("(modules)").unwrap();
+        MODULE_CACHE.with(|cache| cache.borrow_mut().remove(&canonical_path));
+
+        let Expr::List(names) = result else {
+            panic!("Expected a list of module names, got {:?}", result);
+        };
+        assert!(names.contains(&Expr::String(canonical_path.display().to_string())));
+    }
+
+    #[test]
+    fn test_native_modules_arity_error() {
+        let result = eval_system_str("(modules 1)");
+        assert!(matches!(result, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_merge_modules_disjoint_members() {
+        let result = eval_system_str("(merge-modules math string)").unwrap();
+        let Expr::Module(merged) = result else {
+            panic!("Expected a merged module, got {:?}", result);
+        };
+        assert_eq!(
+            merged.path,
+            PathBuf::from("merged:builtin:math+builtin:string")
+        );
+        let merged_env = merged.env.borrow();
+        assert!(merged_env.get("+").is_some());
+        assert!(merged_env.get("concat").is_some());
+    }
+
+    #[test]
+    fn test_native_merge_modules_overlapping_members_prefer_right() {
+        let dir = tempfile::tempdir().unwrap();
+        let left_path = dir.path().join("left.lisp");
+        let right_path = dir.path().join("right.lisp");
+        std::fs::write(&left_path, "(let shared 1) (let only-left 10)").unwrap();
+        std::fs::write(&right_path, "(let shared 2) (let only-right 20)").unwrap();
+
+        let env = Environment::new_with_prelude();
+        let run = |code: String| {
+            let (_, parsed) = parse_expr(&code).expect("test code should parse");
+            eval(
+                &parsed.expect("test code should contain an expression"),
+                env.clone(),
+            )
+        };
+        run(format!(
+            "(let merged (merge-modules (require \"{}\") (require \"{}\")))",
+            left_path.display(),
+            right_path.display()
+        ))
+        .unwrap();
+
+        assert_eq!(run("merged/shared".to_string()), Ok(Expr::Number(2.0)));
+        assert_eq!(run("merged/only-left".to_string()), Ok(Expr::Number(10.0)));
+        assert_eq!(run("merged/only-right".to_string()), Ok(Expr::Number(20.0)));
+
+        for path in [left_path, right_path] {
+            let canonical_path = std::fs::canonicalize(path).unwrap();
+            MODULE_CACHE.with(|cache| cache.borrow_mut().remove(&canonical_path));
+        }
+    }
+
+    #[test]
+    fn test_native_merge_modules_errors() {
+        let result_type = eval_system_str("(merge-modules math 1)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_system_str("(merge-modules math)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_module_members_of_required_module() {
+        let result = eval_system_str("(module-members (require 'map))").unwrap();
+        let Expr::List(names) = result else {
+            panic!("Expected a list of member names, got {:?}", result);
+        };
+        for name in ["get", "contains-key?", "update"] {
+            assert!(
+                names.contains(&Expr::String(name.to_string())),
+                "missing {}",
+                name
+            );
+        }
+        assert!(names.windows(2).all(|pair| match pair {
+            [Expr::String(a), Expr::String(b)] => a < b,
+            _ => false,
+        }));
+    }
+
+    #[test]
+    fn test_native_module_members_of_file_module() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("members.lisp");
+        std::fs::write(&file_path, "(let zeta 1) (let alpha 2)").unwrap();
+
+        let result = eval_system_str(&format!(
+            "(module-members (require \"{}\"))",
+            file_path.display()
+        ))
+        .unwrap();
+        let canonical_path = std::fs::canonicalize(&file_path).unwrap();
+        MODULE_CACHE.with(|cache| cache.borrow_mut().remove(&canonical_path));
+
+        // Only the module's own bindings are listed, not the prelude it was evaluated with,
+        // sorted by name.
+        assert_eq!(
+            result,
+            Expr::List(vec![
+                Expr::String("alpha".to_string()),
+                Expr::String("zeta".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_native_module_members_errors() {
+        let result_type = eval_system_str("(module-members 1)");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_system_str("(module-members)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    #[test]
+    fn test_native_debug_returns_argument() {
+        assert_eq!(eval_system_str("(debug 42)"), Ok(Expr::Number(42.0)));
+        assert_eq!(
+            eval_system_str("(debug '(1 \"two\"))"),
+            Ok(Expr::List(vec![
+                Expr::Number(1.0),
+                Expr::String("two".to_string())
+            ]))
+        );
+        // It can be dropped into the middle of an expression.
+        assert_eq!(eval_system_str("(+ 1 (debug 2))"), Ok(Expr::Number(3.0)));
+    }
+
+    #[test]
+    fn test_native_debug_arity_error() {
+        assert!(matches!(
+            eval_system_str("(debug)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_system_str("(debug 1 2)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+}
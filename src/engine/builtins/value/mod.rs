@@ -0,0 +1,360 @@
+use crate::engine::ast::Expr;
+use crate::engine::eval::{LispError, is_truthy};
+use std::hash::{Hash, Hasher};
+use tracing::{error, trace};
+
+// 64-bit FNV-1a. Unlike std's DefaultHasher, whose algorithm may change between Rust releases,
+// this is fixed, so a given rsp build hashes a value the same way on every run.
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Hashes a value with the fixed FNV-1a hasher behind `hash`.
+/// `Expr` is only `PartialEq` (NaN is not equal to itself), so maps group values by this hash
+/// and compare the candidates in a bucket with `==`.
+pub(crate) fn hash_value<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv1aHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_length(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'length' function");
+    if args.len() != 1 {
+        let msg = format!("length expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let length = match &args[0] {
+        Expr::List(list) | Expr::Vector(list) => list.len(),
+        // Count characters rather than bytes, so multi-byte text has its visible length.
+        Expr::String(s) => s.chars().count(),
+        Expr::Nil => 0,
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "List, Vector or String".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native 'length'");
+            return Err(type_error);
+        }
+    };
+    Ok(Expr::Number(length as f64))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_boolean(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'boolean' function");
+    if args.len() != 1 {
+        let msg = format!("boolean expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    Ok(Expr::Bool(is_truthy(&args[0])))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_not(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'not' function");
+    if args.len() != 1 {
+        let msg = format!("not expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    Ok(Expr::Bool(!is_truthy(&args[0])))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_hash(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'hash' function");
+    if args.len() != 1 {
+        let msg = format!("hash expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    // Stable across runs of the same build, except for native closures, which hash by identity.
+    // Keep 53 bits so the hash is exactly representable as a Number.
+    let hash = hash_value(&args[0]) & ((1u64 << f64::MANTISSA_DIGITS) - 1);
+    Ok(Expr::Number(hash as f64))
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_deep_copy(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'deep-copy' function");
+    if args.len() != 1 {
+        let msg = format!("deep-copy expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    // Values are immutable and rebinding never mutates in place, so a plain clone is a full copy.
+    // Functions and modules still share their environment, which is part of their identity.
+    Ok(args[0].clone())
+}
+
+#[tracing::instrument(skip(args), ret, err)]
+pub fn native_number_to_string(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native 'number->string' function");
+    if args.len() != 1 {
+        let msg = format!("number->string expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+    match &args[0] {
+        number @ (Expr::Number(_) | Expr::Rational { .. }) => {
+            Ok(Expr::String(number.to_lisp_string()))
+        }
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(error = %type_error, "Type error in native 'number->string'");
+            Err(type_error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::env::Environment;
+    use crate::engine::eval::eval;
+    use crate::engine::parser::parse_expr;
+    use crate::logging::init_test_logging;
+
+    // Helper to evaluate a single Lisp expression in a fresh prelude environment.
+    fn eval_value_str(code: &str) -> Result<Expr, LispError> {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let (remaining, parsed_expr_option) = parse_expr(code)
+            .unwrap_or_else(|e| panic!("Test parse error for code '{}': {}", code, e));
+        assert!(
+            remaining.is_empty(),
+            "Unexpected remaining input after parsing in test for code '{}': {}",
+            code,
+            remaining
+        );
+        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
+        eval(&parsed_expr, env)
+    }
+
+    #[test]
+    fn test_native_length_list() {
+        assert_eq!(eval_value_str("(length '(1 2 3))"), Ok(Expr::Number(3.0)));
+        assert_eq!(eval_value_str("(length '())"), Ok(Expr::Number(0.0)));
+        // Nested lists count as single elements
+        assert_eq!(eval_value_str("(length '((1 2) 3))"), Ok(Expr::Number(2.0)));
+    }
+
+    #[test]
+    fn test_native_length_string() {
+        assert_eq!(eval_value_str("(length \"hello\")"), Ok(Expr::Number(5.0)));
+        assert_eq!(eval_value_str("(length \"\")"), Ok(Expr::Number(0.0)));
+        // Characters, not bytes
+        assert_eq!(eval_value_str("(length \"héllo\")"), Ok(Expr::Number(5.0)));
+    }
+
+    #[test]
+    fn test_native_length_vector() {
+        assert_eq!(
+            eval_value_str("(length (vector/list->vector '(1 2 3)))"),
+            Ok(Expr::Number(3.0))
+        );
+    }
+
+    #[test]
+    fn test_native_length_nil() {
+        assert_eq!(eval_value_str("(length nil)"), Ok(Expr::Number(0.0)));
+    }
+
+    #[test]
+    fn test_native_length_errors() {
+        assert!(matches!(
+            eval_value_str("(length 42)"),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_value_str("(length '(1) '(2))"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_boolean_truthy_values() {
+        // Only nil and false are falsy; 0 and the empty string are truthy.
+        assert_eq!(eval_value_str("(boolean 0)"), Ok(Expr::Bool(true)));
+        assert_eq!(eval_value_str("(boolean \"\")"), Ok(Expr::Bool(true)));
+        assert_eq!(eval_value_str("(boolean '())"), Ok(Expr::Bool(true)));
+        assert_eq!(eval_value_str("(boolean true)"), Ok(Expr::Bool(true)));
+    }
+
+    #[test]
+    fn test_native_boolean_falsy_values() {
+        assert_eq!(eval_value_str("(boolean nil)"), Ok(Expr::Bool(false)));
+        assert_eq!(eval_value_str("(boolean false)"), Ok(Expr::Bool(false)));
+    }
+
+    #[test]
+    fn test_native_boolean_arity_error() {
+        assert!(matches!(
+            eval_value_str("(boolean)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_not() {
+        assert_eq!(eval_value_str("(not false)"), Ok(Expr::Bool(true)));
+        assert_eq!(eval_value_str("(not nil)"), Ok(Expr::Bool(true)));
+        assert_eq!(eval_value_str("(not (= 1 2))"), Ok(Expr::Bool(true)));
+        // Everything else is truthy, including 0, "" and the empty list.
+        for value in ["true", "0", "\"\"", "'()", "'a"] {
+            assert_eq!(
+                eval_value_str(&format!("(not {})", value)),
+                Ok(Expr::Bool(false)),
+                "(not {})",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_native_not_arity_errors() {
+        assert!(matches!(
+            eval_value_str("(not)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+        assert!(matches!(
+            eval_value_str("(not true false)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_hash_equal_values_hash_equally() {
+        assert_eq!(
+            eval_value_str("(hash '(1 \"two\" (three)))"),
+            eval_value_str("(hash '(1 \"two\" (three)))")
+        );
+        assert_eq!(eval_value_str("(hash 0)"), eval_value_str("(hash -0)"));
+        assert_eq!(eval_value_str("(hash 1/2)"), eval_value_str("(hash 2/4)"));
+        assert_eq!(
+            eval_value_str("(hash (fn (x) x))"),
+            eval_value_str("(hash (fn (x) x))")
+        );
+    }
+
+    #[test]
+    fn test_native_hash_distinct_values() {
+        assert_ne!(eval_value_str("(hash 1)"), eval_value_str("(hash 2)"));
+        assert_ne!(eval_value_str("(hash \"a\")"), eval_value_str("(hash 'a)"));
+        assert_ne!(
+            eval_value_str("(hash '(1 2))"),
+            eval_value_str("(hash '(2 1))")
+        );
+        assert_ne!(eval_value_str("(hash nil)"), eval_value_str("(hash false)"));
+    }
+
+    #[test]
+    fn test_native_hash_is_a_whole_number() {
+        let Ok(Expr::Number(n)) = eval_value_str("(hash \"bucket\")") else {
+            panic!("hash should return a number");
+        };
+        assert!(n >= 0.0 && n.fract() == 0.0);
+    }
+
+    #[test]
+    fn test_fnv1a_hasher_matches_reference_values() {
+        let fnv = |bytes: &[u8]| {
+            let mut hasher = Fnv1aHasher::default();
+            hasher.write(bytes);
+            hasher.finish()
+        };
+        assert_eq!(fnv(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_native_hash_arity_error() {
+        assert!(matches!(
+            eval_value_str("(hash 1 2)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_deep_copy_nested_list() {
+        assert_eq!(
+            eval_value_str("(deep-copy '(1 (2 (3 \"four\")) nil))"),
+            eval_value_str("'(1 (2 (3 \"four\")) nil)")
+        );
+    }
+
+    #[test]
+    fn test_native_deep_copy_immutable_values() {
+        assert_eq!(eval_value_str("(deep-copy 42)"), Ok(Expr::Number(42.0)));
+        assert_eq!(
+            eval_value_str("(deep-copy \"text\")"),
+            Ok(Expr::String("text".to_string()))
+        );
+        assert_eq!(eval_value_str("(deep-copy nil)"), Ok(Expr::Nil));
+    }
+
+    #[test]
+    fn test_native_deep_copy_arity_error() {
+        assert!(matches!(
+            eval_value_str("(deep-copy)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_native_number_to_string() {
+        let cases = [
+            ("42", "42"),
+            ("2.5", "2.5"),
+            ("-7", "-7"),
+            ("-0.25", "-0.25"),
+            ("1/3", "1/3"),
+        ];
+        for (number, expected) in cases {
+            assert_eq!(
+                eval_value_str(&format!("(number->string {})", number)),
+                Ok(Expr::String(expected.to_string())),
+                "number->string of {}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn test_native_number_to_string_errors() {
+        assert!(matches!(
+            eval_value_str("(number->string \"42\")"),
+            Err(LispError::TypeError { .. })
+        ));
+        assert!(matches!(
+            eval_value_str("(number->string)"),
+            Err(LispError::ArityMismatch(_))
+        ));
+    }
+}
@@ -0,0 +1,368 @@
+//! The `vector` module: operations on vectors.
+//!
+//! A vector is an indexed sequence of values, printed as `[a b c]`. There is no literal
+//! syntax for vectors; they are built from lists with `vector/list->vector`.
+
+use crate::engine::ast::{Expr, LispModule, NativeFunction};
+use crate::engine::env::Environment;
+use crate::engine::eval::{LispError, apply};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::{error, trace};
+
+// Helper function to borrow the elements of a vector argument.
+fn extract_vector<'a>(expr: &'a Expr, op_name: &str) -> Result<&'a [Expr], LispError> {
+    match expr {
+        Expr::Vector(items) => Ok(items),
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Vector".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native vector function");
+            Err(type_error)
+        }
+    }
+}
+
+// Native function for building a vector from a list: (vector/list->vector lst)
+fn native_vector_list_to_vector(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native vector function: vector/list->vector");
+    if args.len() != 1 {
+        let msg = format!("vector/list->vector expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    match &args[0] {
+        Expr::List(items) => Ok(Expr::Vector(items.clone())),
+        Expr::Nil => Ok(Expr::Vector(vec![])),
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "List".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = "vector/list->vector", error = %type_error, "Type error in native vector function");
+            Err(type_error)
+        }
+    }
+}
+
+// Native function for turning a vector back into a list: (vector/vector->list v)
+fn native_vector_vector_to_list(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native vector function: vector/vector->list");
+    if args.len() != 1 {
+        let msg = format!("vector/vector->list expects 1 argument, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_vector(&args[0], "vector/vector->list")?;
+    Ok(Expr::List(items.to_vec()))
+}
+
+// Native function for transforming every element: (vector/map fn v)
+fn native_vector_map(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native vector function: vector/map");
+    if args.len() != 2 {
+        let msg = format!("vector/map expects 2 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_vector(&args[1], "vector/map")?;
+    let mapped = items
+        .iter()
+        .map(|item| apply(args[0].clone(), vec![item.clone()]))
+        .collect::<Result<Vec<Expr>, LispError>>()?;
+    Ok(Expr::Vector(mapped))
+}
+
+// Native function for folding the elements left to right: (vector/reduce fn init v)
+fn native_vector_reduce(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native vector function: vector/reduce");
+    if args.len() != 3 {
+        let msg = format!("vector/reduce expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_vector(&args[2], "vector/reduce")?;
+    let mut acc = args[1].clone();
+    for item in items {
+        acc = apply(args[0].clone(), vec![acc, item.clone()])?;
+    }
+    Ok(acc)
+}
+
+// Helper: validates a slice bound, which must be an integer from 0 to `len` inclusive.
+fn extract_bound(expr: &Expr, len: usize, op_name: &str) -> Result<usize, LispError> {
+    let bound = match expr {
+        Expr::Number(n) => *n,
+        other => {
+            let type_error = LispError::TypeError {
+                expected: "Number".to_string(),
+                found: format!("{:?}", other),
+            };
+            error!(operator = %op_name, error = %type_error, "Type error in native vector function");
+            return Err(type_error);
+        }
+    };
+    if bound < 0.0 || bound.fract() != 0.0 || bound > len as f64 {
+        let msg = format!(
+            "{} index {} is out of range (expected an integer from 0 to {})",
+            op_name, bound, len
+        );
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    Ok(bound as usize)
+}
+
+// Native function for the elements in [start, end): (vector/slice start end v)
+fn native_vector_slice(args: Vec<Expr>) -> Result<Expr, LispError> {
+    trace!("Executing native vector function: vector/slice");
+    if args.len() != 3 {
+        let msg = format!("vector/slice expects 3 arguments, got {}", args.len());
+        error!("{}", msg);
+        return Err(LispError::ArityMismatch(msg));
+    }
+
+    let items = extract_vector(&args[2], "vector/slice")?;
+    let start = extract_bound(&args[0], items.len(), "vector/slice")?;
+    let end = extract_bound(&args[1], items.len(), "vector/slice")?;
+    if start > end {
+        let msg = format!("vector/slice start {} is after end {}", start, end);
+        error!("{}", msg);
+        return Err(LispError::ValueError(msg));
+    }
+    Ok(Expr::Vector(items[start..end].to_vec()))
+}
+
+/// Creates the `vector` module with its associated functions.
+pub fn create_vector_module() -> Expr {
+    trace!("Creating vector module");
+    let vector_env_rc = Environment::new(); // Modules have their own environment
+
+    // Scope the mutable borrow so it's dropped before vector_env_rc is moved
+    {
+        let mut vector_env_borrowed = vector_env_rc.borrow_mut();
+        let functions_to_define: HashMap<String, Expr> = HashMap::from([
+            (
+                "list->vector".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "vector/list->vector".to_string(),
+                    func: native_vector_list_to_vector,
+                }),
+            ),
+            (
+                "vector->list".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "vector/vector->list".to_string(),
+                    func: native_vector_vector_to_list,
+                }),
+            ),
+            (
+                "map".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "vector/map".to_string(),
+                    func: native_vector_map,
+                }),
+            ),
+            (
+                "reduce".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "vector/reduce".to_string(),
+                    func: native_vector_reduce,
+                }),
+            ),
+            (
+                "slice".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "vector/slice".to_string(),
+                    func: native_vector_slice,
+                }),
+            ),
+        ]);
+
+        for (name, func_expr) in functions_to_define {
+            vector_env_borrowed.define(name, func_expr);
+        }
+    }
+
+    Expr::Module(LispModule {
+        path: PathBuf::from("builtin:vector"),
+        env: vector_env_rc,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::eval::eval;
+    use crate::engine::parser::parse_expr;
+    use crate::logging::init_test_logging;
+
+    // Helper to evaluate a Lisp string in an environment that includes the vector module.
+    fn eval_vector_str(code: &str) -> Result<Expr, LispError> {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let (remaining, parsed_expr_option) = parse_expr(code)
+            .unwrap_or_else(|e| panic!("Test parse error for code '{}': {}", code, e));
+        assert!(
+            remaining.is_empty(),
+            "Unexpected remaining input after parsing in test for code '{}': {}",
+            code,
+            remaining
+        );
+        let parsed_expr = parsed_expr_option.expect("Parsed expression should not be None in test");
+        eval(&parsed_expr, env)
+    }
+
+    // Builds the elements of an expected vector of numbers.
+    fn numbers(values: &[f64]) -> Vec<Expr> {
+        values.iter().map(|n| Expr::Number(*n)).collect()
+    }
+
+    // Tests for vector/list->vector and vector/vector->list
+    #[test]
+    fn test_native_vector_list_to_vector() {
+        let result = eval_vector_str("(vector/list->vector '(1 2 3))");
+        assert_eq!(result, Ok(Expr::Vector(numbers(&[1.0, 2.0, 3.0]))));
+        assert_eq!(result.unwrap().to_lisp_string(), "[1 2 3]");
+
+        assert_eq!(
+            eval_vector_str("(vector/list->vector nil)"),
+            Ok(Expr::Vector(vec![]))
+        );
+    }
+
+    #[test]
+    fn test_native_vector_round_trips() {
+        let list = "'(1 \"two\" (3 4) nil)";
+        assert_eq!(
+            eval_vector_str(&format!(
+                "(vector/vector->list (vector/list->vector {}))",
+                list
+            )),
+            eval_vector_str(list)
+        );
+
+        let vector = "(vector/list->vector '(a b))";
+        assert_eq!(
+            eval_vector_str(&format!(
+                "(vector/list->vector (vector/vector->list {}))",
+                vector
+            )),
+            eval_vector_str(vector)
+        );
+
+        // A vector is never equal to the list with the same elements.
+        assert_ne!(
+            eval_vector_str("(vector/list->vector '(1 2))"),
+            eval_vector_str("'(1 2)")
+        );
+    }
+
+    #[test]
+    fn test_native_vector_conversion_errors() {
+        let result_vector_input =
+            eval_vector_str("(vector/list->vector (vector/list->vector '(1)))");
+        assert!(matches!(
+            result_vector_input,
+            Err(LispError::TypeError { .. })
+        ));
+
+        let result_list_input = eval_vector_str("(vector/vector->list '(1 2))");
+        assert!(matches!(
+            result_list_input,
+            Err(LispError::TypeError { .. })
+        ));
+
+        let result_arity = eval_vector_str("(vector/vector->list)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for vector/map and vector/reduce
+    #[test]
+    fn test_native_vector_map_returns_vector() {
+        let result =
+            eval_vector_str("(vector/map (fn (x) (* x 2)) (vector/list->vector '(1 2 3)))");
+        assert_eq!(result, Ok(Expr::Vector(numbers(&[2.0, 4.0, 6.0]))));
+
+        let result_empty =
+            eval_vector_str("(vector/map (fn (x) (* x 2)) (vector/list->vector '()))");
+        assert_eq!(result_empty, Ok(Expr::Vector(vec![])));
+    }
+
+    #[test]
+    fn test_native_vector_reduce() {
+        let result = eval_vector_str("(vector/reduce + 0 (vector/list->vector '(1 2 3 4)))");
+        assert_eq!(result, Ok(Expr::Number(10.0)));
+
+        // Elements are folded in from the left.
+        let result_order = eval_vector_str("(vector/reduce - 10 (vector/list->vector '(1 2)))");
+        assert_eq!(result_order, Ok(Expr::Number(7.0)));
+
+        let result_empty = eval_vector_str("(vector/reduce + 5 (vector/list->vector nil))");
+        assert_eq!(result_empty, Ok(Expr::Number(5.0)));
+    }
+
+    #[test]
+    fn test_native_vector_map_and_reduce_errors() {
+        let result_list = eval_vector_str("(vector/map (fn (x) x) '(1 2))");
+        assert!(matches!(result_list, Err(LispError::TypeError { .. })));
+
+        let result_fn_error =
+            eval_vector_str("(vector/reduce + 0 (vector/list->vector '(1 \"two\")))");
+        assert!(matches!(result_fn_error, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_vector_str("(vector/reduce + 0)");
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+
+    // Tests for vector/slice
+    #[test]
+    fn test_native_vector_slice() {
+        let result = eval_vector_str("(vector/slice 1 3 (vector/list->vector '(a b c d)))");
+        assert_eq!(
+            result,
+            Ok(Expr::Vector(vec![
+                Expr::Symbol("b".to_string()),
+                Expr::Symbol("c".to_string())
+            ]))
+        );
+
+        let result_empty = eval_vector_str("(vector/slice 2 2 (vector/list->vector '(1 2 3)))");
+        assert_eq!(result_empty, Ok(Expr::Vector(vec![])));
+    }
+
+    #[test]
+    fn test_native_vector_slice_full_range() {
+        let vector = "(vector/list->vector '(1 2 3))";
+        assert_eq!(
+            eval_vector_str(&format!("(vector/slice 0 3 {})", vector)),
+            eval_vector_str(vector)
+        );
+    }
+
+    #[test]
+    fn test_native_vector_slice_errors() {
+        let vector = "(vector/list->vector '(1 2 3))";
+        for bounds in ["0 4", "-1 2", "2 1", "0.5 2"] {
+            let result = eval_vector_str(&format!("(vector/slice {} {})", bounds, vector));
+            assert!(
+                matches!(result, Err(LispError::ValueError(_))),
+                "expected a value error for bounds {}, got {:?}",
+                bounds,
+                result
+            );
+        }
+
+        let result_type = eval_vector_str("(vector/slice 0 1 '(1 2))");
+        assert!(matches!(result_type, Err(LispError::TypeError { .. })));
+
+        let result_arity = eval_vector_str(&format!("(vector/slice 0 {})", vector));
+        assert!(matches!(result_arity, Err(LispError::ArityMismatch(_))));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::engine::ast::Expr; // NativeFunction is no longer used directly here
+use crate::engine::ast::{Expr, NativeFn, NativeFunction};
 use crate::engine::builtins::globals::populate_globals;
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -36,6 +36,19 @@ impl Environment {
         env_rc
     }
 
+    /// Creates a new root environment with the prelude, plus any extra bindings.
+    /// Extra bindings are defined after the prelude, so they may shadow prelude names.
+    pub fn new_with_prelude_and(extra: Vec<(String, Expr)>) -> Rc<RefCell<Self>> {
+        let env_rc = Self::new_with_prelude();
+        {
+            let mut env = env_rc.borrow_mut();
+            for (name, value) in extra {
+                env.define(name, value);
+            }
+        }
+        env_rc
+    }
+
     /// Creates a new environment that is enclosed by an outer environment.
     pub fn new_enclosed(outer_env: Rc<RefCell<Environment>>) -> Rc<RefCell<Self>> {
         debug!("Creating new enclosed environment");
@@ -51,6 +64,17 @@ impl Environment {
         self.bindings.insert(name, value);
     }
 
+    /// Defines a Rust function as a callable native in the current environment.
+    pub fn define_native(&mut self, name: &str, func: NativeFn) {
+        self.define(
+            name.to_string(),
+            Expr::NativeFunction(NativeFunction {
+                name: name.to_string(),
+                func,
+            }),
+        );
+    }
+
     /// Attempts to retrieve a variable's value from the environment.
     /// If not found in the current environment, it searches in outer environments.
     pub fn get(&self, name: &str) -> Option<Expr> {
@@ -74,7 +98,6 @@ impl Environment {
 
     /// Returns a clone of all bindings in the current environment.
     /// Useful for inspection, especially in tests or for module introspection.
-    #[allow(dead_code)] // Used by test helpers in other modules (e.g., string module tests)
     pub fn get_all_bindings(&self) -> Vec<(String, Expr)> {
         self.bindings
             .iter()
@@ -87,8 +110,28 @@ impl Environment {
 mod tests {
     use super::*;
     use crate::engine::ast::Expr;
+    use crate::engine::builtins::globals::BUILTIN_MODULES;
+    use crate::engine::eval::{LispError, eval};
+    use crate::engine::parser::parse_expr;
     use crate::logging::init_test_logging; // Use new logging setup
 
+    fn native_triple(args: Vec<Expr>) -> Result<Expr, LispError> {
+        match args.as_slice() {
+            [Expr::Number(n)] => Ok(Expr::Number(n * 3.0)),
+            _ => Err(LispError::ArityMismatch(
+                "triple expects a single number".to_string(),
+            )),
+        }
+    }
+
+    fn eval_in(code: &str, env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
+        let (_, parsed) = parse_expr(code).expect("test code should parse");
+        eval(
+            &parsed.expect("test code should contain an expression"),
+            env,
+        )
+    }
+
     #[test]
     fn define_and_get_in_root_env() {
         init_test_logging();
@@ -142,4 +185,52 @@ mod tests {
         env.borrow_mut().define("x".to_string(), Expr::Number(20.0)); // Redefine
         assert_eq!(env.borrow().get("x"), Some(Expr::Number(20.0)));
     }
+
+    #[test]
+    fn define_native_is_callable_through_eval() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        env.borrow_mut().define_native("triple", native_triple);
+
+        assert_eq!(eval_in("(triple 4)", env.clone()), Ok(Expr::Number(12.0)));
+        // Prelude functions remain available alongside the injected one
+        assert_eq!(eval_in("(+ (triple 2) 1)", env), Ok(Expr::Number(7.0)));
+    }
+
+    #[test]
+    fn new_with_prelude_and_injects_bindings() {
+        init_test_logging();
+        let env = Environment::new_with_prelude_and(vec![
+            (
+                "triple".to_string(),
+                Expr::NativeFunction(NativeFunction {
+                    name: "triple".to_string(),
+                    func: native_triple,
+                }),
+            ),
+            ("answer".to_string(), Expr::Number(42.0)),
+        ]);
+
+        assert_eq!(
+            eval_in("(triple answer)", env.clone()),
+            Ok(Expr::Number(126.0))
+        );
+        assert!(env.borrow().get("+").is_some());
+    }
+
+    #[test]
+    fn builtin_modules_render_with_consistent_paths() {
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        for name in BUILTIN_MODULES {
+            let module = env
+                .borrow()
+                .get(name)
+                .unwrap_or_else(|| panic!("prelude should define the {} module", name));
+            assert_eq!(
+                module.to_lisp_string(),
+                format!("#module[builtin:{}]", name)
+            );
+        }
+    }
 }
@@ -2,8 +2,10 @@ use crate::engine::ast::Expr;
 // builtins direct import might not be needed if all calls are fully qualified to submodules
 use crate::engine::env::Environment;
 use crate::engine::special_forms as special_form_constants; // Renamed for clarity
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tracing::{debug, error, instrument, trace};
 
@@ -46,16 +48,184 @@ pub enum LispError {
     DivisionByZero(String),
     #[error("Value error: {0}")]
     ValueError(String),
+    #[error("Stack overflow: maximum evaluation depth of {0} exceeded")]
+    StackOverflow(usize),
+    #[error("Timeout: evaluation exceeded {0} ms")]
+    Timeout(u64),
     // Add more specific errors as the interpreter develops
 }
 
+/// Default limit on nested `eval` calls. Deep enough for ordinary recursion, but low enough
+/// to fail with `LispError::StackOverflow` before a release build exhausts an 8 MiB native
+/// stack. Debug builds need far more stack per level; the `rsp` binary evaluates on a thread
+/// with a larger stack, and other callers may need to lower the limit.
+pub const DEFAULT_MAX_EVAL_DEPTH: usize = 2000;
+
+thread_local! {
+    // Maximum nesting depth of `eval` on this thread; 0 means unlimited.
+    static MAX_EVAL_DEPTH: Cell<usize> = const { Cell::new(DEFAULT_MAX_EVAL_DEPTH) };
+    // Current nesting depth of `eval` on this thread.
+    static EVAL_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Sets the maximum nesting depth of `eval` for the current thread.
+/// A limit of 0 disables the check entirely, which lets runaway recursion crash the process.
+pub fn set_max_eval_depth(limit: usize) {
+    debug!(limit, "Setting maximum evaluation depth");
+    MAX_EVAL_DEPTH.with(|max| max.set(limit));
+}
+
+/// Returns the maximum nesting depth of `eval` for the current thread (0 means unlimited).
+pub fn max_eval_depth() -> usize {
+    MAX_EVAL_DEPTH.with(|max| max.get())
+}
+
+// Tracks one level of `eval` nesting; the depth is released when the guard is dropped,
+// including when evaluation returns early with an error.
+struct EvalDepthGuard;
+
+impl EvalDepthGuard {
+    fn enter() -> Result<Self, LispError> {
+        let limit = max_eval_depth();
+        let depth = EVAL_DEPTH.with(|depth| depth.get()) + 1;
+        if limit != 0 && depth > limit {
+            error!(limit, "Maximum evaluation depth exceeded");
+            return Err(LispError::StackOverflow(limit));
+        }
+        EVAL_DEPTH.with(|d| d.set(depth));
+        Ok(EvalDepthGuard)
+    }
+}
+
+impl Drop for EvalDepthGuard {
+    fn drop(&mut self) {
+        EVAL_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+thread_local! {
+    // Deadline of the innermost `with-timeout` form on this thread, with its budget in
+    // milliseconds for error reporting; None when evaluation is unbounded.
+    static DEADLINE: Cell<Option<(Instant, u64)>> = const { Cell::new(None) };
+}
+
+/// Starts a time budget of `millis` milliseconds on the current thread. An enclosing deadline
+/// that expires sooner stays in effect. Returns the previous deadline for `finish_deadline`.
+pub(crate) fn start_deadline(millis: u64) -> Option<(Instant, u64)> {
+    let deadline = (Instant::now() + Duration::from_millis(millis), millis);
+    let previous = DEADLINE.with(Cell::get);
+    let effective = match previous {
+        Some(outer) if outer.0 <= deadline.0 => outer,
+        _ => deadline,
+    };
+    DEADLINE.with(|cell| cell.set(Some(effective)));
+    previous
+}
+
+/// Restores the deadline that was active before the matching `start_deadline`.
+pub(crate) fn finish_deadline(previous: Option<(Instant, u64)>) {
+    DEADLINE.with(|cell| cell.set(previous));
+}
+
+// Fails with `LispError::Timeout` once the active deadline, if any, has passed.
+fn check_deadline() -> Result<(), LispError> {
+    match DEADLINE.with(Cell::get) {
+        Some((deadline, millis)) if Instant::now() >= deadline => {
+            error!(millis, "Evaluation deadline exceeded");
+            Err(LispError::Timeout(millis))
+        }
+        _ => Ok(()),
+    }
+}
+
+thread_local! {
+    // Per-name call counts collected while a `profile` form is running; None when idle.
+    static CALL_COUNTS: RefCell<Option<HashMap<String, usize>>> = const { RefCell::new(None) };
+}
+
+/// Starts counting named function calls on the current thread.
+/// Returns the counts of any enclosing profile so they can be restored by `finish_profiling`.
+pub(crate) fn start_profiling() -> Option<HashMap<String, usize>> {
+    CALL_COUNTS.with(|counts| counts.replace(Some(HashMap::new())))
+}
+
+/// Stops counting and returns the calls recorded since the matching `start_profiling`.
+/// Those calls are also added to the enclosing profile, if there is one.
+pub(crate) fn finish_profiling(previous: Option<HashMap<String, usize>>) -> HashMap<String, usize> {
+    let recorded = CALL_COUNTS
+        .with(|counts| counts.replace(previous))
+        .unwrap_or_default();
+    CALL_COUNTS.with(|counts| {
+        if let Some(outer) = counts.borrow_mut().as_mut() {
+            for (name, count) in &recorded {
+                *outer.entry(name.clone()).or_insert(0) += count;
+            }
+        }
+    });
+    recorded
+}
+
+thread_local! {
+    // Environment of the innermost function call made by `eval` on this thread; None when no
+    // call is in progress.
+    static CALLING_ENV: RefCell<Option<Rc<RefCell<Environment>>>> = const { RefCell::new(None) };
+}
+
+/// Returns the environment the running native function was called from, so natives such as
+/// `env-get` can work on the caller's bindings. A native applied by another native (e.g.
+/// through `list/map`) sees the environment of that outer call.
+pub(crate) fn calling_env() -> Option<Rc<RefCell<Environment>>> {
+    CALLING_ENV.with(|calling| calling.borrow().clone())
+}
+
+// Makes an environment the calling environment until dropped, then restores the previous
+// one, including when the call returns early with an error.
+struct CallingEnvGuard(Option<Rc<RefCell<Environment>>>);
+
+impl CallingEnvGuard {
+    fn enter(env: Rc<RefCell<Environment>>) -> Self {
+        CallingEnvGuard(CALLING_ENV.with(|calling| calling.replace(Some(env))))
+    }
+}
+
+impl Drop for CallingEnvGuard {
+    fn drop(&mut self) {
+        CALLING_ENV.with(|calling| *calling.borrow_mut() = self.0.take());
+    }
+}
+
+// Name under which `profile` counts calls of Lisp functions that were never bound by `let`.
+const ANONYMOUS_FUNCTION_NAME: &str = "<anonymous>";
+
+// Records one call of `func` under the function's own name, if profiling is active.
+fn record_call(func: &Expr) {
+    CALL_COUNTS.with(|counts| {
+        if let Some(counts) = counts.borrow_mut().as_mut() {
+            let name = match func {
+                Expr::Function(lisp_fn) => {
+                    lisp_fn.name.as_deref().unwrap_or(ANONYMOUS_FUNCTION_NAME)
+                }
+                Expr::NativeFunction(native_fn) => &native_fn.name,
+                Expr::NativeClosure(native_closure) => &native_closure.name,
+                _ => return,
+            };
+            *counts.entry(name.to_string()).or_insert(0) += 1;
+        }
+    });
+}
+
 #[instrument(skip(expr, env), fields(expr = ?expr), ret, err)]
 pub fn eval(expr: &Expr, env: Rc<RefCell<Environment>>) -> Result<Expr, LispError> {
     trace!("Starting evaluation");
+    let _depth_guard = EvalDepthGuard::enter()?;
+    check_deadline()?;
     match expr {
         Expr::Number(_)
+        | Expr::Rational { .. }
+        | Expr::Vector(_)
         | Expr::Function(_)
         | Expr::NativeFunction(_)
+        | Expr::NativeClosure(_)
         | Expr::Bool(_)
         | Expr::Nil
         | Expr::String(_) // Added String to self-evaluating types
@@ -134,6 +304,33 @@ pub fn eval(expr: &Expr, env: Rc<RefCell<Environment>>) -> Result<Expr, LispErro
                 Expr::Symbol(s) if s == special_form_constants::REQUIRE => {
                     crate::engine::builtins::special_forms::eval_require(&list[1..], Rc::clone(&env))
                 }
+                Expr::Symbol(s) if s == special_form_constants::REQUIRE_ALL => {
+                    crate::engine::builtins::special_forms::eval_require_all(&list[1..], Rc::clone(&env))
+                }
+                Expr::Symbol(s) if s == special_form_constants::DO_TIMES => {
+                    crate::engine::builtins::special_forms::eval_do_times(&list[1..], Rc::clone(&env))
+                }
+                Expr::Symbol(s) if s == special_form_constants::WITH_OUTPUT_TO_STRING => {
+                    crate::engine::builtins::special_forms::eval_with_output_to_string(&list[1..], Rc::clone(&env))
+                }
+                Expr::Symbol(s) if s == special_form_constants::PROFILE => {
+                    crate::engine::builtins::special_forms::eval_profile(&list[1..], Rc::clone(&env))
+                }
+                Expr::Symbol(s) if s == special_form_constants::WITH_TIMEOUT => {
+                    crate::engine::builtins::special_forms::eval_with_timeout(&list[1..], Rc::clone(&env))
+                }
+                Expr::Symbol(s) if s == special_form_constants::COND_THREAD => {
+                    crate::engine::builtins::special_forms::eval_cond_thread(&list[1..], Rc::clone(&env))
+                }
+                Expr::Symbol(s) if s == special_form_constants::THREAD_LAST => {
+                    crate::engine::builtins::special_forms::eval_thread_last(&list[1..], Rc::clone(&env))
+                }
+                Expr::Symbol(s) if s == special_form_constants::SOME_THREAD => {
+                    crate::engine::builtins::special_forms::eval_some_thread(&list[1..], Rc::clone(&env))
+                }
+                Expr::Symbol(s) if s == special_form_constants::BEGIN || s == special_form_constants::DO => {
+                    crate::engine::builtins::special_forms::eval_begin(&list[1..], Rc::clone(&env))
+                }
                 // Attempt to evaluate as a function call
                 _ => {
                     trace!("First element is not a known special form, attempting function call");
@@ -225,7 +422,8 @@ pub fn eval(expr: &Expr, env: Rc<RefCell<Environment>>) -> Result<Expr, LispErro
                     }
 
                     // 3. Apply the function
-                    apply(func_expr_to_call, evaluated_args, Rc::clone(&env)) // Pass cloned env
+                    let _calling_env = CallingEnvGuard::enter(Rc::clone(&env));
+                    apply(func_expr_to_call, evaluated_args)
                 }
             }
         }
@@ -233,12 +431,17 @@ pub fn eval(expr: &Expr, env: Rc<RefCell<Environment>>) -> Result<Expr, LispErro
 }
 
 /// Applies a function (Lisp or native) to a list of evaluated arguments.
-#[instrument(skip(func_expr_to_call, evaluated_args, _calling_env), fields(func = ?func_expr_to_call, args = ?evaluated_args), ret, err)]
-fn apply(
+/// Lisp functions are evaluated in their captured closure, so no calling environment is needed.
+/// Public so that higher-order native functions (e.g. `list/zip-with`) can call back into Lisp.
+#[instrument(skip(func_expr_to_call, evaluated_args), fields(func = ?func_expr_to_call, args = ?evaluated_args), ret, err)]
+pub fn apply(
     func_expr_to_call: Expr, // Renamed parameter for clarity
     evaluated_args: Vec<Expr>,
-    _calling_env: Rc<RefCell<Environment>>, // Use the passed environment, prefixed with _
 ) -> Result<Expr, LispError> {
+    // Natives that loop over `apply` (e.g. `list/map` with a native) never re-enter `eval`.
+    check_deadline()?;
+    // Counted here rather than at the call site, so functions passed as values are included.
+    record_call(&func_expr_to_call);
     match func_expr_to_call {
         // Use the renamed parameter
         Expr::Function(lisp_fn) => {
@@ -280,6 +483,11 @@ fn apply(
             trace!(args = ?evaluated_args, "Calling native function with evaluated arguments");
             (native_fn.func)(evaluated_args)
         }
+        Expr::NativeClosure(native_closure) => {
+            debug!(native_closure_name = %native_closure.name, "Applying NativeClosure");
+            trace!(args = ?evaluated_args, "Calling native closure with evaluated arguments");
+            (native_closure.func)(evaluated_args)
+        }
         _ => {
             error!(evaluated_to = ?func_expr_to_call, "Attempted to call a non-function or non-native-function expression");
             Err(LispError::NotAFunction(format!(
@@ -290,6 +498,12 @@ fn apply(
     }
 }
 
+/// Lisp truthiness: `false` and `nil` are false, every other value is true.
+/// Conditional forms should use this rather than matching on the value themselves.
+pub(crate) fn is_truthy(expr: &Expr) -> bool {
+    !matches!(expr, Expr::Bool(false) | Expr::Nil)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*; // Imports eval, Expr, LispError, Environment, Rc, RefCell
@@ -890,4 +1104,92 @@ mod tests {
             Err(LispError::UndefinedSymbol("/".to_string()))
         );
     }
+
+    #[test]
+    fn is_truthy_covers_every_variant() {
+        use crate::engine::ast::{LispFunction, LispModule, NativeClosure, NativeFunction};
+        use std::path::PathBuf;
+
+        init_test_logging();
+        // Only false and nil are falsy.
+        assert!(!is_truthy(&Expr::Bool(false)));
+        assert!(!is_truthy(&Expr::Nil));
+
+        // Everything else is truthy, including "empty" values.
+        assert!(is_truthy(&Expr::Bool(true)));
+        assert!(is_truthy(&Expr::Number(0.0)));
+        assert!(is_truthy(&Expr::Number(f64::NAN)));
+        assert!(is_truthy(&Expr::Rational { num: 0, den: 1 }));
+        assert!(is_truthy(&Expr::String(String::new())));
+        assert!(is_truthy(&Expr::Symbol("x".to_string())));
+        assert!(is_truthy(&Expr::List(vec![])));
+        assert!(is_truthy(&Expr::Vector(vec![])));
+        assert!(is_truthy(&Expr::Function(LispFunction {
+            name: None,
+            params: vec![],
+            body: Box::new(Expr::Nil),
+            closure: Environment::new(),
+        })));
+        assert!(is_truthy(&Expr::NativeFunction(NativeFunction {
+            name: "noop".to_string(),
+            func: |_| Ok(Expr::Nil),
+        })));
+        assert!(is_truthy(&Expr::NativeClosure(NativeClosure {
+            name: "noop".to_string(),
+            func: Rc::new(|_| Ok(Expr::Nil)),
+        })));
+        assert!(is_truthy(&Expr::Module(LispModule {
+            path: PathBuf::from("builtin:test"),
+            env: Environment::new(),
+        })));
+    }
+
+    // Runs `(countdown n)` against a recursive function, with the given depth limit.
+    fn eval_countdown_with_limit(n: usize, limit: usize) -> Result<Expr, LispError> {
+        use crate::engine::parser::Reader;
+
+        init_test_logging();
+        let env = Environment::new_with_prelude();
+        let code = format!(
+            "(let countdown (fn (n) (if (= n 0) 'done (countdown (- n 1))))) (countdown {})",
+            n
+        );
+        let previous_limit = max_eval_depth();
+        set_max_eval_depth(limit);
+        let mut result = Ok(Expr::Nil);
+        for expr in Reader::new(&code) {
+            result = eval(&expr.unwrap(), Rc::clone(&env));
+        }
+        set_max_eval_depth(previous_limit);
+        result
+    }
+
+    #[test]
+    fn eval_depth_limit_stops_deep_recursion() {
+        assert_eq!(
+            eval_countdown_with_limit(50, 20),
+            Err(LispError::StackOverflow(20))
+        );
+    }
+
+    #[test]
+    fn eval_depth_limit_allows_shallow_recursion() {
+        // Kept shallow: test threads have small native stacks in debug builds.
+        assert_eq!(
+            eval_countdown_with_limit(10, 500),
+            Ok(Expr::Symbol("done".to_string()))
+        );
+        assert_eq!(
+            eval_countdown_with_limit(10, 0),
+            Ok(Expr::Symbol("done".to_string()))
+        );
+    }
+
+    #[test]
+    fn eval_depth_is_released_after_overflow() {
+        assert!(eval_countdown_with_limit(50, 20).is_err());
+        // The failed evaluation must not leave the depth counter raised.
+        assert_eq!(EVAL_DEPTH.with(|depth| depth.get()), 0);
+        assert!(eval_countdown_with_limit(3, 20).is_ok());
+    }
 }
@@ -2,13 +2,14 @@ use nom::{
     IResult,
     Parser,      // Import the Parser trait to use its methods like .map() and .parse()
     branch::alt, // For trying multiple parsers
-    bytes::complete::{is_not, tag}, // Removed escaped_transform
-    character::complete::{char, multispace1, not_line_ending, satisfy}, // Added not_line_ending, Removed none_of
-    combinator::{opt, recognize, verify},                               // Added opt, Added verify
-    multi::{fold_many0, many0, many1, separated_list0}, // Added fold_many0 and many1
-    number::complete::double,                           // For parsing f64 numbers
-    sequence::{delimited, pair, preceded, terminated},  // For sequencing parsers
+    bytes::complete::{is_not, tag},
+    character::complete::{char, digit1, multispace1, not_line_ending, one_of, satisfy},
+    combinator::{map_opt, opt, recognize, verify},
+    multi::{fold_many0, many0, many1, separated_list0},
+    number::complete::double, // For parsing f64 numbers
+    sequence::{delimited, pair, preceded, terminated}, // For sequencing parsers
 };
+use thiserror::Error;
 use tracing::trace; // For logging parser activity
 
 use crate::engine::ast::Expr; // Assuming your AST expressions are in ast::Expr
@@ -20,14 +21,27 @@ fn parse_comment_line(input: &str) -> IResult<&str, &str> {
     recognize(pair(char(';'), not_line_ending)).parse(input)
 }
 
-// Consumes zero or more whitespace characters or full-line comments.
-// Each "ignored item" is either a chunk of whitespace1 or a comment line.
+// Parses a datum comment: `#;` followed by one complete expression, which is discarded.
+// The commented-out expression must still be well-formed, exactly as in Scheme.
+#[tracing::instrument(level = "trace", skip(input), fields(input = %input))]
+fn parse_datum_comment(input: &str) -> IResult<&str, &str> {
+    trace!("Attempting to parse datum comment");
+    recognize(preceded(
+        tag("#;"),
+        preceded(space_or_comment0, expr_recursive_impl),
+    ))
+    .parse(input)
+}
+
+// Consumes zero or more whitespace characters, full-line comments, or datum comments.
+// Each "ignored item" is either a chunk of whitespace1, a comment line, or a datum comment.
 #[tracing::instrument(level = "trace", skip(input), fields(input = %input))]
 fn space_or_comment0(input: &str) -> IResult<&str, &str> {
     trace!("Attempting to parse zero or more spaces/comments");
     recognize(many0(alt((
         multispace1, // Consumes whitespace including newlines
         parse_comment_line,
+        parse_datum_comment,
     ))))
     .parse(input)
 }
@@ -36,7 +50,12 @@ fn space_or_comment0(input: &str) -> IResult<&str, &str> {
 #[tracing::instrument(level = "trace", skip(input), fields(input = %input))]
 fn space_or_comment1(input: &str) -> IResult<&str, &str> {
     trace!("Attempting to parse one or more spaces/comments");
-    recognize(many1(alt((multispace1, parse_comment_line)))).parse(input)
+    recognize(many1(alt((
+        multispace1,
+        parse_comment_line,
+        parse_datum_comment,
+    ))))
+    .parse(input)
 }
 
 // Parses a number (f64) into an Expr::Number - raw token, no surrounding whitespace handling.
@@ -46,6 +65,21 @@ fn parse_number_raw(input: &str) -> IResult<&str, Expr> {
     double.map(Expr::Number).parse(input)
 }
 
+// Parses a rational literal such as 1/3 or -2/4 into a normalized Expr::Rational - raw token.
+// A zero denominator or a component that doesn't fit in i64 is not a rational literal.
+#[tracing::instrument(level = "trace", skip(input), fields(input = %input))]
+fn parse_rational_raw(input: &str) -> IResult<&str, Expr> {
+    trace!("Attempting to parse raw rational token");
+    map_opt(
+        (
+            recognize(pair(opt(one_of("+-")), digit1)),
+            preceded(char('/'), digit1),
+        ),
+        |(num, den): (&str, &str)| Expr::rational(num.parse().ok()?, den.parse().ok()?),
+    )
+    .parse(input)
+}
+
 // Parses the keyword "true" into an Expr::Bool(true) - raw token.
 #[tracing::instrument(level = "trace", skip(input), fields(input = %input))]
 fn parse_true_raw(input: &str) -> IResult<&str, Expr> {
@@ -180,6 +214,7 @@ fn list_raw(input: &str) -> IResult<&str, Expr> {
 fn expr_recursive_impl(input: &str) -> IResult<&str, Expr> {
     trace!("Attempting to parse core expression token (recursive_impl)");
     alt((
+        parse_rational_raw, // Must precede numbers, or `1/3` would parse as 1 followed by `/3`
         parse_number_raw,
         parse_true_raw,
         parse_false_raw,
@@ -211,6 +246,127 @@ pub fn parse_expr(input: &str) -> IResult<&str, Option<Expr>> {
     .parse(input)
 }
 
+/// An error produced by [`Reader`] when the input does not form a valid expression.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The input ended in the middle of an expression (e.g. an unclosed list or string).
+    #[error("more input needed")]
+    Incomplete,
+    /// The input could not be parsed as an expression.
+    #[error("could not parse input starting at '{0}'")]
+    Invalid(String),
+}
+
+/// Reads expressions one at a time from a string of source code.
+///
+/// This is the shared "parse a sequence of expressions" loop used by source evaluation and
+/// module loading. Whitespace and comments between expressions are skipped. Once an error is
+/// returned the reader is exhausted.
+#[derive(Debug, Clone)]
+pub struct Reader<'a> {
+    input: &'a str,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Reader { input }
+    }
+
+    /// Returns the input that has not been consumed yet.
+    pub fn remaining(&self) -> &'a str {
+        self.input
+    }
+
+    /// Reads the next expression, or returns `None` once only whitespace/comments remain.
+    pub fn next_expr(&mut self) -> Option<Result<Expr, ParseError>> {
+        let result = match parse_expr(self.input) {
+            Ok((remaining, Some(expr))) => {
+                self.input = remaining;
+                return Some(Ok(expr));
+            }
+            // Nothing but whitespace/comments left.
+            Ok(("", None)) => return None,
+            // `parse_expr` returns `None` without consuming input when the rest is not an
+            // expression, so classify whatever follows the leading whitespace/comments.
+            Ok((remaining, None)) => Err(classify_parse_failure(remaining)),
+            Err(nom::Err::Incomplete(_)) => Err(ParseError::Incomplete),
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+                Err(classify_parse_failure(e.input))
+            }
+        };
+        trace!(result = ?result, "Reader stopped on parse failure");
+        self.input = "";
+        Some(result)
+    }
+}
+
+impl Iterator for Reader<'_> {
+    type Item = Result<Expr, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_expr()
+    }
+}
+
+// Decides whether unparseable input is merely unfinished (so more input could complete it)
+// or genuinely malformed.
+fn classify_parse_failure(input: &str) -> ParseError {
+    if needs_more_input(input) {
+        ParseError::Incomplete
+    } else {
+        ParseError::Invalid(input.lines().next().unwrap_or_default().to_string())
+    }
+}
+
+// Scans for an unclosed list, an unterminated string, or a dangling quote character or
+// datum comment (`#;` still waiting for the expression it discards).
+fn needs_more_input(input: &str) -> bool {
+    let mut depth: usize = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut in_comment = false;
+    let mut dangling_quote = false;
+    let mut previous = None;
+
+    for c in input.chars() {
+        let after_hash = previous.replace(c) == Some('#');
+        if in_comment {
+            in_comment = c != '\n';
+            continue;
+        }
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            // `#;` comments out the next expression, not the rest of the line.
+            ';' if after_hash => {
+                dangling_quote = true;
+                continue;
+            }
+            ';' => in_comment = true,
+            '"' => in_string = true,
+            '(' => depth += 1,
+            ')' => match depth.checked_sub(1) {
+                Some(d) => depth = d,
+                // An unmatched closing paren can never be fixed by more input.
+                None => return false,
+            },
+            _ => {}
+        }
+        if !c.is_whitespace() && c != ';' {
+            dangling_quote = c == '\'';
+        }
+    }
+
+    in_string || depth > 0 || dangling_quote
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,6 +438,83 @@ mod tests {
         assert_eq!(result, Ok(("abc", Some(Expr::Number(123.0)))));
     }
 
+    #[test]
+    fn test_parse_rational() {
+        init_test_logging();
+        let result = parse_expr("1/3");
+        assert_eq!(result, Ok(("", Some(Expr::Rational { num: 1, den: 3 }))));
+        // Literals are normalized as they are read.
+        let result_reduced = parse_expr("-2/4");
+        assert_eq!(
+            result_reduced,
+            Ok(("", Some(Expr::Rational { num: -1, den: 2 })))
+        );
+        let result_whole = parse_expr("4/2");
+        assert_eq!(result_whole, Ok(("", Some(Expr::Number(2.0)))));
+        let result_in_list = parse_expr("(+ 1/3 1/6)");
+        assert_eq!(
+            result_in_list,
+            Ok((
+                "",
+                Some(Expr::List(vec![
+                    Expr::Symbol("+".to_string()),
+                    Expr::Rational { num: 1, den: 3 },
+                    Expr::Rational { num: 1, den: 6 },
+                ]))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_datum_comment_skips_list() {
+        init_test_logging();
+        let result = parse_expr("#;(ignored (nested)) (kept)");
+        assert_eq!(
+            result,
+            Ok(("", Some(Expr::List(vec![Expr::Symbol("kept".to_string())]))))
+        );
+    }
+
+    #[test]
+    fn test_parse_datum_comment_skips_atom() {
+        init_test_logging();
+        let result = parse_expr("#; 42 \"kept\"");
+        assert_eq!(result, Ok(("", Some(Expr::String("kept".to_string())))));
+    }
+
+    #[test]
+    fn test_parse_datum_comment_inside_list() {
+        init_test_logging();
+        let result = parse_expr("(+ 1 #;2 3 #;(4 5))");
+        assert_eq!(
+            result,
+            Ok((
+                "",
+                Some(Expr::List(vec![
+                    Expr::Symbol("+".to_string()),
+                    Expr::Number(1.0),
+                    Expr::Number(3.0),
+                ]))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_reader_datum_comment_needs_complete_datum() {
+        init_test_logging();
+        let exprs: Vec<_> = Reader::new("#;(a b) 1 #;2").collect();
+        assert_eq!(exprs, vec![Ok(Expr::Number(1.0))]);
+
+        let unfinished: Vec<_> = Reader::new("#;(a b").collect();
+        assert_eq!(unfinished, vec![Err(ParseError::Incomplete)]);
+
+        let dangling: Vec<_> = Reader::new("1 #;").collect();
+        assert_eq!(
+            dangling,
+            vec![Ok(Expr::Number(1.0)), Err(ParseError::Incomplete)]
+        );
+    }
+
     #[test]
     fn test_parse_not_a_number() {
         init_test_logging();
@@ -887,4 +1120,72 @@ mod tests {
             ))
         );
     }
+
+    fn read_all(input: &str) -> Vec<Result<Expr, ParseError>> {
+        init_test_logging();
+        Reader::new(input).collect()
+    }
+
+    #[test]
+    fn test_reader_multiple_expressions() {
+        assert_eq!(
+            read_all("1 ; comment\n (a b)\n  \"s\""),
+            vec![
+                Ok(Expr::Number(1.0)),
+                Ok(Expr::List(vec![
+                    Expr::Symbol("a".to_string()),
+                    Expr::Symbol("b".to_string())
+                ])),
+                Ok(Expr::String("s".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reader_eof_cleanly() {
+        assert_eq!(read_all(""), vec![]);
+        assert_eq!(read_all("  ; only a comment\n\n"), vec![]);
+
+        let mut reader = Reader::new("x ");
+        assert_eq!(reader.next_expr(), Some(Ok(Expr::Symbol("x".to_string()))));
+        assert_eq!(reader.remaining(), "");
+        assert_eq!(reader.next_expr(), None);
+        assert_eq!(reader.next_expr(), None);
+    }
+
+    #[test]
+    fn test_reader_incomplete_input() {
+        assert_eq!(
+            read_all("(+ 1 2) (+ 3"),
+            vec![
+                Ok(Expr::List(vec![
+                    Expr::Symbol("+".to_string()),
+                    Expr::Number(1.0),
+                    Expr::Number(2.0)
+                ])),
+                Err(ParseError::Incomplete)
+            ]
+        );
+        assert_eq!(
+            read_all("\"unterminated"),
+            vec![Err(ParseError::Incomplete)]
+        );
+        assert_eq!(
+            read_all("(a ; comment with )\n"),
+            vec![Err(ParseError::Incomplete)]
+        );
+        assert_eq!(read_all("'"), vec![Err(ParseError::Incomplete)]);
+    }
+
+    #[test]
+    fn test_reader_invalid_input_stops_iteration() {
+        let mut reader = Reader::new("1 ) 2");
+        init_test_logging();
+        assert_eq!(reader.next_expr(), Some(Ok(Expr::Number(1.0))));
+        assert_eq!(
+            reader.next_expr(),
+            Some(Err(ParseError::Invalid(") 2".to_string())))
+        );
+        assert_eq!(reader.next_expr(), None);
+    }
 }
@@ -6,9 +6,35 @@ pub const QUOTE: &str = "quote";
 pub const FN: &str = "fn";
 pub const IF: &str = "if";
 pub const REQUIRE: &str = "require";
+pub const REQUIRE_ALL: &str = "require-all";
+pub const DO_TIMES: &str = "do-times";
+pub const WITH_OUTPUT_TO_STRING: &str = "with-output-to-string";
+pub const PROFILE: &str = "profile";
+pub const WITH_TIMEOUT: &str = "with-timeout";
+pub const COND_THREAD: &str = "cond->";
+pub const THREAD_LAST: &str = "->>";
+pub const SOME_THREAD: &str = "some->";
+pub const BEGIN: &str = "begin";
+pub const DO: &str = "do"; // Alias for `begin`
 
 /// Array of special form names. These are reserved and cannot be used as variable names in `let`.
-pub const SPECIAL_FORMS: &[&str] = &[LET, QUOTE, FN, IF, REQUIRE];
+pub const SPECIAL_FORMS: &[&str] = &[
+    LET,
+    QUOTE,
+    FN,
+    IF,
+    REQUIRE,
+    REQUIRE_ALL,
+    DO_TIMES,
+    WITH_OUTPUT_TO_STRING,
+    PROFILE,
+    WITH_TIMEOUT,
+    COND_THREAD,
+    THREAD_LAST,
+    SOME_THREAD,
+    BEGIN,
+    DO,
+];
 
 /// Checks if a given name is a special form.
 ///
@@ -32,6 +58,17 @@ mod tests {
         assert!(is_special_form("fn"));
         assert!(is_special_form("if"));
         assert!(is_special_form("require"));
+        assert!(is_special_form("require-all"));
+        assert!(is_special_form("do-times"));
+        assert!(is_special_form("with-output-to-string"));
+        assert!(is_special_form("profile"));
+        assert!(is_special_form("with-timeout"));
+        assert!(is_special_form("cond->"));
+        assert!(is_special_form("->>"));
+        assert!(is_special_form("some->"));
+        assert!(is_special_form("begin"));
+        assert!(is_special_form("do"));
+        assert!(!is_special_form("env-get"));
         assert!(!is_special_form("my-function"));
         assert!(!is_special_form(""));
     }
@@ -43,5 +80,15 @@ mod tests {
         assert_eq!(FN, "fn");
         assert_eq!(IF, "if");
         assert_eq!(REQUIRE, "require");
+        assert_eq!(REQUIRE_ALL, "require-all");
+        assert_eq!(DO_TIMES, "do-times");
+        assert_eq!(WITH_OUTPUT_TO_STRING, "with-output-to-string");
+        assert_eq!(PROFILE, "profile");
+        assert_eq!(WITH_TIMEOUT, "with-timeout");
+        assert_eq!(COND_THREAD, "cond->");
+        assert_eq!(THREAD_LAST, "->>");
+        assert_eq!(SOME_THREAD, "some->");
+        assert_eq!(BEGIN, "begin");
+        assert_eq!(DO, "do");
     }
 }
@@ -0,0 +1,94 @@
+//! rsp: a small Lisp interpreter that can be embedded as a library.
+//!
+//! The simplest entry point is [`run_source`], which evaluates a string of Lisp
+//! code in a fresh prelude environment. For more control (custom bindings, reusing
+//! an environment across calls), build an [`engine::env::Environment`] and use
+//! [`evaluate_source`] directly.
+
+pub mod engine;
+pub mod logging;
+
+use crate::engine::ast::Expr;
+use crate::engine::env::Environment;
+use crate::engine::eval::eval;
+use crate::engine::parser::{ParseError, Reader};
+use std::cell::RefCell; // For thread_local!
+use std::collections::HashMap; // For MODULE_CACHE
+use std::path::PathBuf; // For MODULE_CACHE keys
+use std::rc::Rc;
+use tracing::info;
+
+// Global cache for loaded modules, using thread_local for single-threaded context.
+// Key: Canonicalized absolute path to the module file.
+// Value: The Expr::Module representing the loaded module.
+thread_local! {
+    pub(crate) static MODULE_CACHE: RefCell<HashMap<PathBuf, crate::engine::ast::Expr>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Evaluates a sequence of Lisp expressions from a string.
+///
+/// Args:
+///     source_content: The string containing Lisp expressions.
+///     env: The environment to evaluate expressions in.
+///     source_name: A descriptive name for the source (e.g., "string expression", "file.lisp") for error messages.
+///
+/// Returns:
+///     Ok((Option<Expr>, bool)): The last evaluated expression and a flag indicating if any expressions were evaluated.
+///     Err(String): An error message if parsing or evaluation fails.
+#[tracing::instrument(skip(source_content, env), fields(source_name = %source_name))]
+pub fn evaluate_source(
+    source_content: &str,
+    env: Rc<RefCell<Environment>>,
+    source_name: &str,
+) -> Result<(Option<Expr>, bool), String> {
+    let mut last_result: Option<Expr> = None;
+    let mut expressions_evaluated = false;
+
+    for parsed in Reader::new(source_content) {
+        let ast = match parsed {
+            Ok(ast) => ast,
+            Err(ParseError::Incomplete) => {
+                let err_msg = format!("Parsing incomplete in {}: More input needed.", source_name);
+                info!(parsing_error = %err_msg, "Parsing failed in {}", source_name);
+                return Err(err_msg);
+            }
+            Err(e) => {
+                let err_msg = format!("Parsing Error in {}: {}", source_name, e);
+                info!(parsing_error = %err_msg, "Parsing failed in {}", source_name);
+                return Err(err_msg);
+            }
+        };
+
+        expressions_evaluated = true;
+        info!(parsed_ast = ?ast, "Successfully parsed expression from {}", source_name);
+        match eval(&ast, Rc::clone(&env)) {
+            Ok(result) => {
+                info!(evaluation_result = ?result, "Evaluation successful in {}", source_name);
+                last_result = Some(result);
+            }
+            Err(e) => {
+                let err_msg = format!("Evaluation Error in {}: {}", source_name, e);
+                info!(evaluation_error = %e, "Evaluation error from {}", source_name);
+                return Err(err_msg); // Stop on first evaluation error
+            }
+        }
+    }
+    Ok((last_result, expressions_evaluated))
+}
+
+/// Evaluates Lisp source code in a fresh environment with the prelude loaded.
+///
+/// Returns the value of the last expression, or `None` if the source contained no
+/// expressions (e.g. only whitespace or comments). Parse and evaluation errors are
+/// reported as human-readable messages.
+///
+/// ```
+/// use rsp::engine::ast::Expr;
+///
+/// assert_eq!(rsp::run_source("(+ 1 2)"), Ok(Some(Expr::Number(3.0))));
+/// ```
+pub fn run_source(src: &str) -> Result<Option<Expr>, String> {
+    let env = Environment::new_with_prelude();
+    evaluate_source(src, env, "source").map(|(last_result, _)| last_result)
+}
@@ -71,21 +71,21 @@ impl Highlighter for LispHighlighter {
         while current_pos < line.len() {
             let mut found_match_at_current_pos = false;
             for (regex, style_opt) in &tokens_regexes {
-                if let Some(mat) = regex.find_at(line, current_pos) {
-                    if mat.start() == current_pos {
-                        // Append part before match (should be empty if current_pos is at mat.start())
-                        // highlighted_line.push_str(&line[current_pos..mat.start()]);
-
-                        let matched_text = &line[mat.start()..mat.end()];
-                        if let Some(style) = style_opt {
-                            highlighted_line.push_str(&matched_text.style(*style).to_string());
-                        } else {
-                            highlighted_line.push_str(matched_text); // No style / default
-                        }
-                        current_pos = mat.end();
-                        found_match_at_current_pos = true;
-                        break;
+                if let Some(mat) = regex.find_at(line, current_pos)
+                    && mat.start() == current_pos
+                {
+                    // Append part before match (should be empty if current_pos is at mat.start())
+                    // highlighted_line.push_str(&line[current_pos..mat.start()]);
+
+                    let matched_text = &line[mat.start()..mat.end()];
+                    if let Some(style) = style_opt {
+                        highlighted_line.push_str(&matched_text.style(*style).to_string());
+                    } else {
+                        highlighted_line.push_str(matched_text); // No style / default
                     }
+                    current_pos = mat.end();
+                    found_match_at_current_pos = true;
+                    break;
                 }
             }
 
@@ -20,18 +20,17 @@ pub(crate) fn load_history_from_path(
     rl: &mut Editor<ReplHelper, DefaultHistory>, // Updated Editor type
     history_path: &PathBuf,
 ) {
-    if let Some(parent_dir) = history_path.parent() {
-        if !parent_dir.exists() {
-            if let Err(e) = fs::create_dir_all(parent_dir) {
-                warn!(
-                    "Failed to create history directory {}: {}",
-                    parent_dir.display(),
-                    e
-                );
-                // If directory creation fails, we probably can't load/save history.
-                // The subsequent load_history will likely fail and log it.
-            }
-        }
+    if let Some(parent_dir) = history_path.parent()
+        && !parent_dir.exists()
+        && let Err(e) = fs::create_dir_all(parent_dir)
+    {
+        warn!(
+            "Failed to create history directory {}: {}",
+            parent_dir.display(),
+            e
+        );
+        // If directory creation fails, we probably can't load/save history.
+        // The subsequent load_history will likely fail and log it.
     }
     if history_path.exists() {
         if let Err(err) = rl.load_history(history_path) {
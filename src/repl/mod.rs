@@ -1,4 +1,4 @@
-use crate::engine::env::Environment;
+use rsp::engine::env::Environment;
 use rustyline::Editor;
 use rustyline::error::ReadlineError;
 use rustyline::history::DefaultHistory;
@@ -54,9 +54,9 @@ pub fn start_repl(env: Rc<RefCell<Environment>>) -> anyhow::Result<()> {
                     break;
                 }
 
-                match crate::evaluate_source(trimmed_input, Rc::clone(&env), "repl") {
+                match rsp::evaluate_source(trimmed_input, Rc::clone(&env), "repl") {
                     Ok((Some(result), _)) => {
-                        println!("{:?}", result);
+                        println!("{}", result.to_result_string());
                     }
                     Ok((None, true)) => {
                         // Valid input, no printable result (e.g., define)
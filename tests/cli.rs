@@ -0,0 +1,55 @@
+//! Runs the `rsp` binary and checks what it prints.
+
+use std::process::{Command, Output};
+
+// Runs `rsp run` with the given extra arguments and checks that it exited cleanly.
+fn rsp_output(args: &[&str]) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_rsp"))
+        .arg("run")
+        .args(args)
+        .output()
+        .expect("rsp binary should run");
+    assert!(output.status.success(), "rsp exited with {}", output.status);
+    output
+}
+
+// Runs `rsp run` with the given extra arguments and returns its standard output.
+fn run_rsp(args: &[&str]) -> String {
+    String::from_utf8(rsp_output(args).stdout).expect("rsp output should be UTF-8")
+}
+
+#[test]
+fn run_expr_prints_result_as_lisp_value() {
+    assert_eq!(run_rsp(&["-e", "(+ 10 (* 2 3))"]), "16\n");
+    assert_eq!(run_rsp(&["-e", "(/ 1 3)"]), "0.3333333333333333\n");
+}
+
+#[test]
+fn run_expr_result_respects_precision() {
+    assert_eq!(run_rsp(&["--precision", "3", "-e", "(/ 1 3)"]), "0.333\n");
+    // Only display is rounded; converting a number to a string stays exact.
+    assert_eq!(
+        run_rsp(&["--precision", "3", "-e", "(number->string (/ 1 3))"]),
+        "\"0.3333333333333333\"\n"
+    );
+}
+
+#[test]
+fn run_expr_quotes_string_results() {
+    assert_eq!(run_rsp(&["-e", "(string/concat \"a\" \"b\")"]), "\"ab\"\n");
+}
+
+#[test]
+fn run_expr_reports_deep_recursion_at_default_depth() {
+    // Runaway recursion must hit the evaluation limit, not the native stack, even in debug builds.
+    let output = rsp_output(&[
+        "-e",
+        "(let f (fn (n) (if (= n 0) 0 (+ 1 (f (- n 1)))))) (f 100000)",
+    ]);
+    let stderr = String::from_utf8(output.stderr).expect("rsp output should be UTF-8");
+    assert!(
+        stderr.contains("maximum evaluation depth of 2000 exceeded"),
+        "unexpected stderr: {}",
+        stderr
+    );
+}
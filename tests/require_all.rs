@@ -0,0 +1,56 @@
+//! Loads a directory of modules with `require-all`, the way a plugin host would.
+
+use rsp::engine::ast::Expr;
+use rsp::run_source;
+use std::fs;
+
+#[test]
+fn require_all_loads_every_lisp_file_keyed_by_stem() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("alpha.lisp"), "(let value 1)").unwrap();
+    fs::write(dir.path().join("beta.lisp"), "(let value 2)").unwrap();
+    // Files without the .lisp extension are ignored.
+    fs::write(dir.path().join("notes.txt"), "not lisp").unwrap();
+
+    let src = format!(
+        r#"
+        (let mods (require-all "{}"))
+        (list/length mods)
+        "#,
+        dir.path().display()
+    );
+    assert_eq!(run_source(&src), Ok(Some(Expr::Number(2.0))));
+
+    let sum_src = format!(
+        r#"
+        (let mods (require-all "{}"))
+        (let alpha (map/get mods "alpha"))
+        (let beta (map/get mods "beta"))
+        (+ alpha/value beta/value)
+        "#,
+        dir.path().display()
+    );
+    assert_eq!(run_source(&sum_src), Ok(Some(Expr::Number(3.0))));
+}
+
+#[test]
+fn require_all_rejects_non_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    let file_path = dir.path().join("single.lisp");
+    fs::write(&file_path, "(let value 1)").unwrap();
+
+    let err = run_source(&format!(r#"(require-all "{}")"#, file_path.display())).unwrap_err();
+    assert!(
+        err.contains("Evaluation Error"),
+        "unexpected message: {}",
+        err
+    );
+
+    let missing = dir.path().join("missing");
+    let err = run_source(&format!(r#"(require-all "{}")"#, missing.display())).unwrap_err();
+    assert!(
+        err.contains("Module not found"),
+        "unexpected message: {}",
+        err
+    );
+}
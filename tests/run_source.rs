@@ -0,0 +1,80 @@
+//! Exercises the library entry points the way an embedding application would.
+
+use rsp::engine::ast::{Expr, NativeFunction};
+use rsp::engine::env::Environment;
+use rsp::engine::eval::LispError;
+use rsp::{evaluate_source, run_source};
+
+#[test]
+fn run_source_returns_last_expression() {
+    let result = run_source("(let x 20) (let y 22) (+ x y)");
+    assert_eq!(result, Ok(Some(Expr::Number(42.0))));
+}
+
+#[test]
+fn run_source_uses_prelude_and_builtin_modules() {
+    let result = run_source(r#"(let s (require 'string)) (s/concat "a" "b")"#);
+    assert_eq!(result, Ok(Some(Expr::String("ab".to_string()))));
+}
+
+#[test]
+fn run_source_empty_input_yields_none() {
+    assert_eq!(run_source(""), Ok(None));
+    assert_eq!(run_source("   ; only a comment\n"), Ok(None));
+}
+
+#[test]
+fn run_source_reports_evaluation_errors() {
+    let err = run_source("(+ 1 undefined-symbol)").unwrap_err();
+    assert!(
+        err.contains("Evaluation Error"),
+        "unexpected message: {}",
+        err
+    );
+    assert!(
+        err.contains("undefined-symbol"),
+        "unexpected message: {}",
+        err
+    );
+}
+
+#[test]
+fn run_source_reports_parse_errors() {
+    let err = run_source("(+ 1 2").unwrap_err();
+    assert!(err.contains("Pars"), "unexpected message: {}", err);
+}
+
+#[test]
+fn run_source_starts_from_a_fresh_environment() {
+    assert_eq!(run_source("(let leaked 1)"), Ok(Some(Expr::Number(1.0))));
+    assert!(run_source("leaked").is_err());
+}
+
+fn native_shout(args: Vec<Expr>) -> Result<Expr, LispError> {
+    match args.as_slice() {
+        [Expr::String(s)] => Ok(Expr::String(s.to_uppercase())),
+        _ => Err(LispError::ArityMismatch(
+            "shout expects a single string".to_string(),
+        )),
+    }
+}
+
+#[test]
+fn evaluate_source_with_custom_environment() {
+    let env = Environment::new_with_prelude_and(vec![(
+        "shout".to_string(),
+        Expr::NativeFunction(NativeFunction {
+            name: "shout".to_string(),
+            func: native_shout,
+        }),
+    )]);
+
+    let (result, evaluated) = evaluate_source(r#"(shout "hi")"#, env.clone(), "embedder").unwrap();
+    assert!(evaluated);
+    assert_eq!(result, Some(Expr::String("HI".to_string())));
+
+    // Bindings persist across calls that share an environment.
+    evaluate_source("(let greeting \"hey\")", env.clone(), "embedder").unwrap();
+    let (result, _) = evaluate_source("(shout greeting)", env, "embedder").unwrap();
+    assert_eq!(result, Some(Expr::String("HEY".to_string())));
+}